@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::input::{ButtonState, InputPlugin};
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use criterion::{criterion_group, criterion_main, Criterion};
+use learning_bevy::{
+    Ball, GameState, KeyBindings, MatchResult, Player, PongPlugin, RallySpeedPlugin, Scorer,
+};
+use rand::random;
+
+const GAMES: u32 = 1000;
+// the game is tuned for one physics step per frame at 60fps
+const FRAME_SECS: f64 = 1. / 60.;
+// an hour of play, well past any real match
+const MAX_FRAMES: u32 = 60 * 60 * 60;
+// how far the ball can be from where the paddle means to meet it
+// before it's chased
+const DEAD_ZONE: f32 = 5.;
+// how far off the paddle's middle it means to meet the ball, either
+// way. Near the end of the paddle, so with the dead zone it misses
+// now and then, about as often as the AI does.
+const MAX_AIM_OFFSET: f32 = 28.;
+
+struct GameOutcome {
+    winner: Scorer,
+    secs: f64,
+}
+
+fn headless_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, InputPlugin, PongPlugin))
+        // without it the AI returns every ball and no game ever ends
+        .add_plugins(RallySpeedPlugin)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            FRAME_SECS,
+        )))
+        // normally added by the rendering plugins
+        .init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<ColorMaterial>>();
+
+    // the paddles are placed against the window's sides
+    let window = app.world_mut().spawn(Window::default()).id();
+    (app, window)
+}
+
+fn send_key(app: &mut App, window: Entity, key_code: KeyCode, state: ButtonState) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state,
+        window,
+    });
+}
+
+fn ball_position(world: &mut World) -> Option<Vec3> {
+    world
+        .query_filtered::<&Transform, With<Ball>>()
+        .iter(world)
+        .next()
+        .map(|transform| transform.translation)
+}
+
+// The naive strategy: go wherever the ball is, give or take `aim`.
+// Meeting it dead center every time would send it back flat forever.
+fn toward_ball(world: &mut World, bindings: &KeyBindings, aim: f32) -> Option<KeyCode> {
+    let ball_y = ball_position(world)?.y;
+    let paddle_y = world
+        .query_filtered::<&Transform, With<Player>>()
+        .iter(world)
+        .next()?
+        .translation
+        .y
+        + aim;
+
+    if ball_y > paddle_y + DEAD_ZONE {
+        Some(bindings.player_up)
+    } else if ball_y < paddle_y - DEAD_ZONE {
+        Some(bindings.player_down)
+    } else {
+        None
+    }
+}
+
+fn play_game() -> GameOutcome {
+    let (mut app, window) = headless_app();
+    // gets everything spawned, then starts the match from the menu
+    app.update();
+    // what the game plays with when the key bindings feature is left out
+    let bindings = KeyBindings::default();
+    send_key(&mut app, window, KeyCode::Space, ButtonState::Pressed);
+    app.update();
+    send_key(&mut app, window, KeyCode::Space, ButtonState::Released);
+
+    let mut held = None;
+    let mut aim = 0.;
+    let mut last_ball_x = None;
+    let mut heading_back = false;
+    for frame in 0..MAX_FRAMES {
+        if *app.world().resource::<State<GameState>>().get() == GameState::GameOver {
+            return GameOutcome {
+                winner: app.world().resource::<MatchResult>().winner,
+                secs: frame as f64 * FRAME_SECS,
+            };
+        }
+
+        // a new aim for every ball that heads back this way
+        let ball_x = ball_position(app.world_mut()).map(|position| position.x);
+        if let (Some(x), Some(last_x)) = (ball_x, last_ball_x) {
+            if x < last_x && !heading_back {
+                aim = (random::<f32>() * 2. - 1.) * MAX_AIM_OFFSET;
+            }
+            heading_back = x < last_x;
+        }
+        last_ball_x = ball_x;
+
+        let wanted = toward_ball(app.world_mut(), &bindings, aim);
+        if wanted != held {
+            if let Some(key) = held {
+                send_key(&mut app, window, key, ButtonState::Released);
+            }
+            if let Some(key) = wanted {
+                send_key(&mut app, window, key, ButtonState::Pressed);
+            }
+            held = wanted;
+        }
+        app.update();
+    }
+    panic!("no one won within {MAX_FRAMES} frames");
+}
+
+fn simulation(c: &mut Criterion) {
+    let outcomes: Vec<GameOutcome> = (0..GAMES).map(|_| play_game()).collect();
+    let average_secs = outcomes.iter().map(|outcome| outcome.secs).sum::<f64>() / GAMES as f64;
+    let ai_wins = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome.winner, Scorer::Ai))
+        .count();
+    println!(
+        "{GAMES} games: {average_secs:.1}s long on average, the AI won {:.1}%",
+        ai_wins as f64 / GAMES as f64 * 100.
+    );
+
+    // whole games are slow, so only a few of them are timed
+    let mut group = c.benchmark_group("simulation");
+    group.sample_size(10);
+    group.bench_function("game", |b| b.iter(play_game));
+    group.finish();
+}
+
+criterion_group!(benches, simulation);
+criterion_main!(benches);
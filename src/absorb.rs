@@ -0,0 +1,157 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::key_bindings::KeyBindings;
+use crate::{
+    handle_collisions, move_paddles, Ball, HitCount, LastHitBy, Player, Position, Shape, Velocity,
+    PADDLE_SPEED,
+};
+
+const MAX_HOLD_SECS: f32 = 1.;
+// launch angle with the paddle moving at full speed
+const MAX_LAUNCH_ANGLE: f32 = FRAC_PI_4;
+
+// A ball caught by a paddle, carried along with it until launched
+#[derive(Component)]
+struct Attached {
+    paddle: Entity,
+    offset: Vec2,
+    // speed the ball came in with, and leaves with
+    speed: f32,
+    timer: Timer,
+}
+
+#[derive(Component)]
+struct HoldIndicator;
+
+pub struct PaddleAbsorbPlugin;
+
+impl Plugin for PaddleAbsorbPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_hold_indicator)
+            .add_systems(
+                FixedUpdate,
+                (
+                    absorb_balls.after(handle_collisions),
+                    carry_attached_balls.after(absorb_balls).after(move_paddles),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, update_hold_indicator);
+    }
+}
+
+fn spawn_hold_indicator(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(15.),
+                width: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle {
+                    visibility: Visibility::Hidden,
+                    ..TextBundle::from_section(
+                        "HOLD!",
+                        TextStyle {
+                            font_size: 40.,
+                            color: Color::srgb_u8(120, 220, 255),
+                            ..default()
+                        },
+                    )
+                },
+                HoldIndicator,
+            ));
+        });
+}
+
+// Catches balls the player's paddle has just hit while absorb is held
+fn absorb_balls(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    balls: Query<
+        (Entity, &HitCount, &LastHitBy, &Position, &Shape, &Velocity),
+        (Changed<HitCount>, Without<Attached>),
+    >,
+    player: Query<(Entity, &Position, &Shape), With<Player>>,
+) {
+    if !keyboard_input.pressed(bindings.absorb) {
+        return;
+    }
+    let Ok((paddle, paddle_position, paddle_shape)) = player.get_single() else {
+        return;
+    };
+
+    for (entity, hit_count, last_hit_by, position, shape, velocity) in &balls {
+        if hit_count.0 == 0 || last_hit_by.0 != Some(paddle) {
+            continue;
+        }
+
+        // held just off the paddle's face, at the height it hit,
+        // so it isn't counted as hitting the paddle every step
+        let gap = paddle_shape.0.x / 2. + shape.0.x + 1.;
+        let face = -paddle_position.0.x.signum() * gap;
+        commands.entity(entity).insert(Attached {
+            paddle,
+            offset: Vec2::new(face, position.0.y - paddle_position.0.y),
+            speed: velocity.0.length(),
+            timer: Timer::from_seconds(MAX_HOLD_SECS, TimerMode::Once),
+        });
+    }
+}
+
+fn carry_attached_balls(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut balls: Query<(Entity, &mut Attached, &mut Position, &mut Velocity), With<Ball>>,
+    paddles: Query<(&Position, &Velocity), Without<Ball>>,
+    time: Res<Time>,
+) {
+    for (entity, mut attached, mut position, mut velocity) in &mut balls {
+        let Ok((paddle_position, paddle_velocity)) = paddles.get(attached.paddle) else {
+            commands.entity(entity).remove::<Attached>();
+            continue;
+        };
+        position.0 = paddle_position.0 + attached.offset;
+
+        let timed_out = attached.timer.tick(time.delta()).finished();
+        if !timed_out && keyboard_input.pressed(bindings.absorb) {
+            velocity.0 = Vec2::ZERO;
+            continue;
+        }
+
+        // the faster the paddle is moving, the steeper the launch
+        let steer = (paddle_velocity.0.y / PADDLE_SPEED).clamp(-1., 1.);
+        let angle = steer * MAX_LAUNCH_ANGLE;
+        let x_dir = attached.offset.x.signum();
+        velocity.0 = Vec2::new(x_dir * angle.cos(), angle.sin()) * attached.speed;
+        commands.entity(entity).remove::<Attached>();
+    }
+}
+
+fn update_hold_indicator(
+    attached: Query<(), With<Attached>>,
+    mut indicator: Query<&mut Visibility, With<HoldIndicator>>,
+) {
+    if let Ok(mut visibility) = indicator.get_single_mut() {
+        let holding = !attached.is_empty();
+        let wanted = if holding {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use bevy::prelude::*;
+
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::team::Team;
+use crate::{
+    handle_player_input, move_paddles, Ai, Ball, Paddle, Position, Shape, Velocity, GUTTER_HEIGHT,
+};
+
+// How fast the AI paddle can chase the ball. Even the hardest is
+// slower than the player's paddle, so well angled shots can beat it.
+#[derive(Resource, Reflect, Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub enum AiDifficulty {
+    Easy,
+    #[default]
+    Medium,
+    Hard,
+}
+
+impl AiDifficulty {
+    pub fn max_speed(self) -> f32 {
+        match self {
+            AiDifficulty::Easy => 2.5,
+            AiDifficulty::Medium => 3.5,
+            AiDifficulty::Hard => 4.5,
+        }
+    }
+
+    // as kept in the match history
+    // how often, on average, the AI loses track of the ball, per second
+    fn mistakes_per_sec(self) -> f32 {
+        match self {
+            AiDifficulty::Easy => 0.25,
+            AiDifficulty::Medium => 0.12,
+            AiDifficulty::Hard => 0.05,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AiDifficulty::Easy => "easy",
+            AiDifficulty::Medium => "medium",
+            AiDifficulty::Hard => "hard",
+        }
+    }
+}
+
+// The right paddle is played by the computer, unless the game was
+// started with `--two-player` to leave it to the AI up and down keys
+#[derive(Resource)]
+pub struct AiOpponent(pub bool);
+
+pub struct AiPlugin;
+
+impl Plugin for AiPlugin {
+    fn build(&self, app: &mut App) {
+        let two_player = std::env::args().any(|arg| arg == "--two-player");
+        app.insert_resource(AiOpponent(!two_player))
+            .init_resource::<AiDifficulty>()
+            // takes over from the keys, then `move_paddles` clamps it to the field
+            .add_systems(
+                FixedUpdate,
+                ai_paddle_control
+                    .after(handle_player_input)
+                    .before(move_paddles)
+                    .run_if(ai_playing)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn ai_playing(opponent: Res<AiOpponent>) -> bool {
+    opponent.0
+}
+
+const MISTAKE_SECS: f32 = 0.6;
+
+// Set while the AI has lost track of the ball. Its paddles stop dead
+// until it finds it again, with a "?!" over the AI paddle meanwhile.
+#[derive(Resource, Default)]
+pub struct AiMakingMistake(pub bool);
+
+#[derive(Resource)]
+struct MistakeTimer(Timer);
+
+impl Default for MistakeTimer {
+    fn default() -> Self {
+        MistakeTimer(Timer::from_seconds(MISTAKE_SECS, TimerMode::Once))
+    }
+}
+
+#[derive(Component)]
+struct MistakeBubble;
+
+// Left out of the headless tests and the bench, which need an AI
+// that plays the same way every time
+pub struct AiMistakesPlugin;
+
+impl Plugin for AiMistakesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AiMakingMistake>()
+            .init_resource::<MistakeTimer>()
+            .add_systems(Startup, spawn_mistake_bubble)
+            .add_systems(
+                OnEnter(InMatch),
+                (
+                    reset_resource::<AiMakingMistake>,
+                    reset_resource::<MistakeTimer>,
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                make_mistakes
+                    .before(ai_paddle_control)
+                    .run_if(ai_playing)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, show_mistake_bubble);
+    }
+}
+
+fn make_mistakes(
+    mut mistake: ResMut<AiMakingMistake>,
+    mut timer: ResMut<MistakeTimer>,
+    difficulty: Res<AiDifficulty>,
+    time: Res<Time>,
+) {
+    if mistake.0 {
+        if timer.0.tick(time.delta()).finished() {
+            mistake.0 = false;
+        }
+    } else if rand::random::<f32>() < difficulty.mistakes_per_sec() * time.delta_seconds() {
+        mistake.0 = true;
+        timer.0.reset();
+    }
+}
+
+fn spawn_mistake_bubble(mut commands: Commands) {
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "?!",
+                TextStyle {
+                    font_size: 24.,
+                    color: Color::srgb_u8(255, 210, 60),
+                    ..default()
+                },
+            ),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        MistakeBubble,
+    ));
+}
+
+// Fades in and back out over the mistake, above the AI paddle
+fn show_mistake_bubble(
+    mistake: Res<AiMakingMistake>,
+    timer: Res<MistakeTimer>,
+    state: Res<State<GameState>>,
+    mut bubbles: Query<(&mut Transform, &mut Visibility, &mut Text), With<MistakeBubble>>,
+    paddles: Query<(&Transform, &Shape), (With<Ai>, Without<MistakeBubble>)>,
+) {
+    let Ok((mut transform, mut visibility, mut text)) = bubbles.get_single_mut() else {
+        return;
+    };
+    let paddle = paddles.get_single();
+    let (true, GameState::Playing | GameState::Paused, Ok((paddle_transform, shape))) =
+        (mistake.0, state.get(), paddle)
+    else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    *visibility = Visibility::Visible;
+    transform.translation = paddle_transform.translation + Vec3::new(0., shape.0.y / 2. + 18., 1.);
+    let alpha = (timer.0.fraction() * std::f32::consts::PI).sin();
+    let color = &mut text.sections[0].style.color;
+    *color = color.with_alpha(alpha);
+}
+
+// Folds a height past the top or bottom of the field back in,
+// the way the ball bounces off the gutters
+fn bounce_into_field(y: f32, half_height: f32) -> f32 {
+    let span = 2. * half_height;
+    let folded = (y + half_height).rem_euclid(2. * span);
+    let folded = if folded > span {
+        2. * span - folded
+    } else {
+        folded
+    };
+    folded - half_height
+}
+
+// In team mode the AI plays both of team B's paddles. Each covers its
+// own band of the field, top to bottom, and waits in the middle of it.
+fn ai_paddle_control(
+    balls: Query<(&Position, &Velocity, &Shape), With<Ball>>,
+    mut paddles: Query<
+        (&Position, &mut Velocity, &Shape, Has<Ai>, Option<&Team>),
+        (With<Paddle>, Without<Ball>),
+    >,
+    window: Query<&Window>,
+    difficulty: Res<AiDifficulty>,
+    mistake: Option<Res<AiMakingMistake>>,
+) {
+    let mut ai: Vec<_> = paddles
+        .iter_mut()
+        .filter(|(_, _, _, is_ai, team)| *is_ai || *team == Some(&Team::B))
+        .map(|(position, velocity, shape, _, _)| (position.0, shape.0.x, velocity))
+        .collect();
+    let (Ok(window), Some((paddle, paddle_width, _))) = (window.get_single(), ai.first()) else {
+        return;
+    };
+    // lost track of the ball, so it stops where it is
+    if mistake.is_some_and(|mistake| mistake.0) {
+        for (_, _, mut velocity) in ai {
+            velocity.0.y = 0.;
+        }
+        return;
+    }
+    let face_x = paddle.x - paddle_width / 2.;
+    let field_half_height = window.resolution.height() / 2. - GUTTER_HEIGHT;
+
+    // with several balls, go for whichever gets here first
+    let intercept = balls
+        .iter()
+        .filter(|(_, ball_velocity, _)| ball_velocity.0.x > 0.)
+        .map(|(position, ball_velocity, shape)| {
+            let radius = shape.0.x;
+            let steps = ((face_x - radius - position.0.x) / ball_velocity.0.x).max(0.);
+            let half_height = field_half_height - radius;
+            let y = position.0.y + ball_velocity.0.y * steps;
+            (steps, bounce_into_field(y, half_height))
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, y)| y);
+
+    // teammates block each other, so they never swap bands
+    ai.sort_by(|a, b| b.0.y.total_cmp(&a.0.y));
+    let band = 2. * field_half_height / ai.len() as f32;
+    let max_speed = difficulty.max_speed();
+    for (index, (paddle, _, mut velocity)) in ai.into_iter().enumerate() {
+        let top = field_half_height - band * index as f32;
+        let bottom = top - band;
+        // back to the middle of the band while nothing is coming its way
+        let target_y = match intercept {
+            Some(y) if (bottom..=top).contains(&y) => y,
+            _ => (top + bottom) / 2.,
+        };
+        velocity.0.y = (target_y - paddle.y).clamp(-max_speed, max_speed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rotating_playfield::PlayfieldRotation;
+    use crate::team::{TeamMode, TeamPlugin};
+    use crate::test_mode::{headless_app, run_frames};
+    use crate::Score;
+
+    #[test]
+    fn ai_plays_team_b_teammate_too() {
+        let mut app = headless_app(vec![]);
+        app.add_plugins(TeamPlugin)
+            .insert_resource(TeamMode(true))
+            .init_resource::<PlayfieldRotation>();
+        run_frames(&mut app, 1);
+        // low and heading right, past where the teammate waits
+        let mut balls = app
+            .world_mut()
+            .query_filtered::<(&mut Position, &mut Velocity), With<Ball>>();
+        let (mut position, mut velocity) = balls.single_mut(app.world_mut());
+        position.0 = Vec2::new(0., -260.);
+        velocity.0 = Vec2::new(5., 0.);
+        run_frames(&mut app, 60);
+
+        let mut teammates = app
+            .world_mut()
+            .query_filtered::<(&Position, &Team), (With<Paddle>, Without<Ai>)>();
+        let teammate_y = teammates
+            .iter(app.world())
+            .find(|(_, team)| **team == Team::B)
+            .map(|(position, _)| position.0.y)
+            .unwrap();
+        assert!(
+            teammate_y < -240.,
+            "the teammate should have gone for the ball"
+        );
+    }
+
+    #[test]
+    fn ai_stops_while_making_a_mistake() {
+        let mut app = headless_app(vec![]);
+        // no timer ticking it off, so the mistake lasts the whole test
+        app.insert_resource(AiMakingMistake(true));
+        run_frames(&mut app, 1);
+        // heading for the AI well above where it waits
+        let mut balls = app
+            .world_mut()
+            .query_filtered::<(&mut Position, &mut Velocity), With<Ball>>();
+        let (mut position, mut velocity) = balls.single_mut(app.world_mut());
+        position.0 = Vec2::new(0., 200.);
+        velocity.0 = Vec2::new(5., 0.);
+        run_frames(&mut app, 30);
+
+        let mut paddles = app
+            .world_mut()
+            .query_filtered::<&Position, (With<Paddle>, With<Ai>)>();
+        assert_eq!(paddles.single(app.world()).0.y, 0.);
+    }
+
+    #[test]
+    fn ai_paddle_returns_a_shot_off_the_gutter() {
+        // bounces off the top gutter on the way, well away from where
+        // the AI paddle starts
+        let mut app = headless_app(vec![]);
+        run_frames(&mut app, 1);
+        let mut balls = app
+            .world_mut()
+            .query_filtered::<&mut Velocity, With<Ball>>();
+        balls.single_mut(app.world_mut()).0 = Vec2::new(5., 3.);
+        run_frames(&mut app, 130);
+
+        let score = app.world().resource::<Score>();
+        assert_eq!((score.player, score.ai), (0, 0));
+        let mut balls = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        assert!(
+            balls.single(app.world()).0.x < 0.,
+            "the ball should be returned"
+        );
+    }
+}
@@ -0,0 +1,502 @@
+use std::collections::VecDeque;
+
+use bevy::math::bounding::{Aabb2d, BoundingCircle};
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+use rand::random;
+
+use crate::collision::{
+    ActiveCollisionBackend, Collision, CollisionBackend, SweepCollisionBackend,
+};
+use crate::game_state::{GameState, InMatch};
+use crate::gutter::Health;
+use crate::paddle::{move_paddles, Paddle};
+use crate::post_score_freeze::post_score_freeze_over;
+use crate::rebound_paddle::BounceCount;
+use crate::score::{detect_scoring, Scored, Scorer};
+use crate::wall_breaker::GutterGaps;
+use crate::{Position, Shape, Velocity};
+
+pub const BALL_RADIUS: f32 = 5.;
+// the range of ball speeds a serve and a rally produce, in px per
+// physics step, which runs at the same rate whatever the frame rate
+pub const MIN_BALL_SPEED: f32 = 4.;
+pub const MAX_BALL_SPEED: f32 = 12.;
+// how much of a moving paddle's vertical speed a ball picks up from it
+const TRANSFER_FACTOR: f32 = 0.5;
+// the angle off straight a return leaves at when it comes off the
+// very end of a paddle, under the steep shot bonus of `score`
+const MAX_BOUNCE_ANGLE: f32 = 50. * std::f32::consts::PI / 180.;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Ball;
+
+#[derive(Bundle)]
+pub struct BallBundle {
+    pub ball: Ball,
+    pub position: Position,
+    pub velocity: Velocity,
+    pub shape: Shape,
+    pub hit_count: HitCount,
+    pub last_hit_by: LastHitBy,
+    pub position_history: PositionHistory,
+    pub prev_position: PrevPosition,
+}
+
+impl BallBundle {
+    pub fn new(v_x: f32, v_y: f32) -> Self {
+        BallBundle {
+            ball: Ball,
+            position: Position(Vec2::new(0., 0.)),
+            velocity: Velocity(Vec2::new(v_x, v_y)),
+            shape: Shape(Vec2::new(BALL_RADIUS, BALL_RADIUS)),
+            hit_count: HitCount(0),
+            last_hit_by: LastHitBy(None),
+            position_history: PositionHistory::default(),
+            prev_position: PrevPosition::default(),
+        }
+    }
+}
+
+// Number of paddle hits this ball has taken in the current rally
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct HitCount(pub u32);
+
+// The paddle that last returned this ball, if any
+#[derive(Component)]
+pub struct LastHitBy(pub Option<Entity>);
+
+// Where the ball was one physics step ago, which `move_ball` steps on from
+#[derive(Component, Default)]
+pub struct PrevPosition(pub Vec2);
+
+// Where the ball has been over the last `max_len` physics steps, oldest first
+#[derive(Component)]
+pub struct PositionHistory {
+    pub positions: VecDeque<Vec2>,
+    pub max_len: usize,
+}
+
+impl Default for PositionHistory {
+    fn default() -> Self {
+        PositionHistory {
+            positions: VecDeque::new(),
+            max_len: 10,
+        }
+    }
+}
+
+// Handles to the ball's mesh and material, kept around so
+// balls can be respawned without re-creating the assets
+#[derive(Resource)]
+pub struct BallAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<ColorMaterial>,
+}
+
+impl BallAssets {
+    pub fn bundle(&self) -> MaterialMesh2dBundle<ColorMaterial> {
+        MaterialMesh2dBundle {
+            mesh: self.mesh.clone().into(),
+            material: self.material.clone(),
+            ..default()
+        }
+    }
+}
+
+// Holds every ball in place while set, e.g. while the tutorial explains things
+#[derive(Resource, Default)]
+pub struct BallFrozen(pub bool);
+
+#[derive(Event)]
+pub struct PaddleHit;
+
+// Sent by `detect_collisions` for every bounce, off paddles and gutters
+// alike. The bounce itself, the sound and the effects all follow from it.
+#[derive(Event, Debug, Copy, Clone)]
+pub struct BallCollision {
+    pub ball: Entity,
+    // what the ball ran into
+    pub entity: Entity,
+    // the side of the ball that was hit
+    pub side: Collision,
+}
+
+pub struct BallPlugin;
+
+impl Plugin for BallPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BallFrozen>()
+            .add_event::<PaddleHit>()
+            .add_event::<BallCollision>()
+            .add_systems(Startup, spawn_ball)
+            .add_systems(OnExit(InMatch), clear_balls)
+            .add_systems(OnEnter(InMatch), serve_first_ball)
+            .add_systems(
+                FixedUpdate,
+                (
+                    move_ball
+                        .run_if(ball_in_play)
+                        .run_if(post_score_freeze_over),
+                    // against where the paddles are now, not a step ago
+                    detect_collisions.after(move_ball).after(move_paddles),
+                    handle_collisions.after(detect_collisions),
+                    reset_ball.after(detect_scoring),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn spawn_ball(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    println!("Spawning ball");
+
+    let shape = Mesh::from(Circle::new(BALL_RADIUS));
+    let material = ColorMaterial::from_color(Color::srgb_u8(50, 100, 200));
+
+    // `Assets::add` will load these into memory and return a
+    // `Handle` (an ID) to these assets. When all references
+    // to this `Handle` are cleaned up the asset is cleaned up.
+
+    let ball_assets = BallAssets {
+        mesh: meshes.add(shape),
+        material: materials.add(material),
+    };
+
+    // Here we are using `spawn` instead of `spawn_empty`
+    // followed by an `insert`. They mean the same thing,
+    // letting us spawn many components on a new entity at once.
+
+    commands.spawn((BallBundle::new(5., 0.), ball_assets.bundle()));
+    commands.insert_resource(ball_assets);
+}
+
+fn ball_in_play(frozen: Res<BallFrozen>) -> bool {
+    !frozen.0
+}
+
+// Störmer-Verlet: the ball carries on from where it was a step ago, and
+// its velocity is read back off the two positions. Velocity is in px
+// per physics step, so dt is 1. Bounces, serves, gravity and the rest
+// move or kick the ball through `Position` and `Velocity`, so a ball
+// touched since the last step is anchored to its new velocity first.
+pub fn move_ball(
+    // Give me all positions that also contain a `Ball` component
+    mut balls: Query<
+        (
+            &mut Position,
+            &mut PrevPosition,
+            &mut Velocity,
+            &mut PositionHistory,
+        ),
+        With<Ball>,
+    >,
+) {
+    for (mut position, mut prev_position, mut velocity, mut history) in &mut balls {
+        if position.is_changed() || velocity.is_changed() {
+            prev_position.0 = position.0 - velocity.0;
+        }
+
+        let next = 2. * position.0 - prev_position.0;
+        prev_position.0 = position.0;
+        position.0 = next;
+        velocity.0 = next - prev_position.0;
+
+        history.positions.push_back(position.0);
+        if history.positions.len() > history.max_len {
+            history.positions.pop_front();
+        }
+    }
+}
+
+// Puts every ball that hit something back where it first touched,
+// and leaves what happens next to the readers of `BallCollision`.
+// Sweeps unless `Physics2DPlugin` picked another backend.
+pub fn detect_collisions(
+    mut balls: Query<(Entity, &mut Position, &Velocity, &Shape), With<Ball>>,
+    others: Query<(Entity, &Position, &Shape, Option<&GutterGaps>), Without<Ball>>,
+    mut collisions: EventWriter<BallCollision>,
+    collision_backend: Option<Res<ActiveCollisionBackend>>,
+) {
+    let collision_backend: &dyn CollisionBackend = match &collision_backend {
+        Some(active) => &*active.0,
+        None => &SweepCollisionBackend,
+    };
+    for (ball, mut ball_position, ball_velocity, ball_shape) in &mut balls {
+        for (entity, position, shape, gaps) in &others {
+            // balls fly straight through holes knocked in a gutter
+            if let Some(gaps) = gaps {
+                if gaps.0.iter().any(|gap| gap.contains(&ball_position.0.x)) {
+                    continue;
+                }
+            }
+
+            let ball_circle = BoundingCircle::new(ball_position.0, ball_shape.0.x);
+            let other_rect = Aabb2d::new(position.0, shape.0 / 2.);
+            if let Some((side, contact)) =
+                collision_backend.ball_vs_rect(ball_circle, ball_velocity.0, other_rect)
+            {
+                // Still touching after a bounce isn't another hit. Left
+                // in, it would pull the ball back every step, for good.
+                if ball_velocity.0.dot(side.way_out()) > 0. {
+                    continue;
+                }
+                ball_position.0 = contact;
+                collisions.send(BallCollision { ball, entity, side });
+            }
+        }
+    }
+}
+
+// The bounce itself, along with the wear and hit counting that goes with it
+pub fn handle_collisions(
+    mut collisions: EventReader<BallCollision>,
+    mut balls: Query<
+        (
+            &mut Velocity,
+            &mut HitCount,
+            &mut LastHitBy,
+            &Position,
+            &Shape,
+        ),
+        With<Ball>,
+    >,
+    mut others: Query<
+        (
+            &Position,
+            &Shape,
+            Option<&mut Health>,
+            Option<&mut BounceCount>,
+            Option<&Velocity>,
+            Has<Paddle>,
+        ),
+        Without<Ball>,
+    >,
+    mut paddle_hits: EventWriter<PaddleHit>,
+) {
+    for collision in collisions.read() {
+        let (
+            Ok((mut ball_velocity, mut hit_count, mut last_hit_by, ball_position, ball_shape)),
+            Ok((position, shape, health, bounce_count, velocity, is_paddle)),
+        ) = (
+            balls.get_mut(collision.ball),
+            others.get_mut(collision.entity),
+        )
+        else {
+            continue;
+        };
+
+        // Always away from the side that was hit, rather than flipped. A
+        // paddle moving onto the ball leaves them overlapping for a few
+        // steps, and flipping every step would keep the ball inside.
+        match collision.side {
+            Collision::Top => ball_velocity.0.y = -ball_velocity.0.y.abs(),
+            Collision::Bottom => ball_velocity.0.y = ball_velocity.0.y.abs(),
+            Collision::Left => ball_velocity.0.x = ball_velocity.0.x.abs(),
+            Collision::Right => ball_velocity.0.x = -ball_velocity.0.x.abs(),
+        }
+
+        // destructible things wear down with every bounce
+        if let Some(mut health) = health {
+            health.0 = health.0.saturating_sub(1);
+        }
+        if let Some(mut bounce_count) = bounce_count {
+            bounce_count.0 += 1;
+        }
+
+        if is_paddle {
+            // Like classic Pong, where the ball meets the paddle sets the
+            // angle it leaves at, straight from the middle and steepest
+            // off the ends. Hits on the paddle's ends just bounce.
+            if matches!(collision.side, Collision::Left | Collision::Right) {
+                let reach = shape.0.y / 2. + ball_shape.0.x;
+                let offset = ((ball_position.0.y - position.0.y) / reach).clamp(-1., 1.);
+                let angle = offset * MAX_BOUNCE_ANGLE;
+                let direction = Vec2::new(ball_velocity.0.x.signum() * angle.cos(), angle.sin());
+                ball_velocity.0 = direction * ball_velocity.0.length();
+            }
+            // timing a return with the paddle moving steepens it
+            if let Some(velocity) = velocity {
+                ball_velocity.0.y += velocity.0.y * TRANSFER_FACTOR;
+                ball_velocity.0 = ball_velocity.0.clamp_length_max(MAX_BALL_SPEED);
+            }
+            hit_count.0 += 1;
+            last_hit_by.0 = Some(collision.entity);
+            paddle_hits.send(PaddleHit);
+        }
+    }
+}
+
+pub fn random_serve_velocity(x_dir: f32) -> Vec2 {
+    let random_v_y = (random::<f32>() - 0.5) * 3.;
+    let random_v_y = random_v_y + random_v_y.signum() * 4.;
+
+    let random_v_x_mag = 4. + random::<f32>() * 3.;
+
+    Vec2::new(x_dir * random_v_x_mag, random_v_y)
+}
+
+// However many balls a match ended with, none outlive it
+fn clear_balls(mut commands: Commands, balls: Query<Entity, With<Ball>>) {
+    for entity in &balls {
+        commands.entity(entity).despawn();
+    }
+}
+
+// The next match starts from one ball back in the middle. The first
+// match plays the ball spawned at startup.
+fn serve_first_ball(
+    mut commands: Commands,
+    balls: Query<(), With<Ball>>,
+    ball_assets: Res<BallAssets>,
+) {
+    if balls.is_empty() {
+        commands.spawn((BallBundle::new(5., 0.), ball_assets.bundle()));
+    }
+}
+
+pub fn reset_ball(
+    mut commands: Commands,
+    balls: Query<Entity, With<Ball>>,
+    ball_assets: Res<BallAssets>,
+    mut events: EventReader<Scored>,
+) {
+    // a score ends the rally for every ball in play,
+    // so we only need to serve once for the latest score
+    if let Some(event) = events.read().last() {
+        for entity in &balls {
+            commands.entity(entity).despawn();
+        }
+
+        // get the current score
+        let x_dir = match event.0 {
+            Scorer::Player => -1.,
+            Scorer::Ai => 1.,
+        };
+
+        let velocity = random_serve_velocity(x_dir);
+        commands.spawn((
+            BallBundle::new(velocity.x, velocity.y),
+            ball_assets.bundle(),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::AiOpponent;
+    use crate::test_mode::{headless_app, run_frames};
+
+    #[test]
+    fn moved_ball_carries_on_at_its_velocity() {
+        let mut app = headless_app(vec![]);
+        run_frames(&mut app, 10);
+
+        // as a portal or a serve would, without touching the velocity
+        let mut balls = app
+            .world_mut()
+            .query_filtered::<(&mut Position, &Velocity), With<Ball>>();
+        let (mut position, velocity) = balls.single_mut(app.world_mut());
+        position.0 = Vec2::new(0., 100.);
+        let velocity = velocity.0;
+        run_frames(&mut app, 1);
+
+        let (position, after) = balls.single(app.world());
+        assert_eq!(after.0, velocity);
+        assert!(position.0.distance(Vec2::new(0., 100.) + velocity) < 1e-3);
+    }
+
+    #[test]
+    fn moving_paddle_steepens_the_return() {
+        // the AI paddle drops a little, then is moving up through the
+        // middle as the ball reaches it, so the return isn't angled by
+        // where it hit
+        let mut app = headless_app(vec![
+            (60, KeyCode::KeyX, true),
+            (64, KeyCode::KeyX, false),
+            (113, KeyCode::KeyW, true),
+            (120, KeyCode::KeyW, false),
+        ]);
+        app.insert_resource(AiOpponent(false));
+        run_frames(&mut app, 130);
+
+        let mut balls = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let velocity = balls.single(app.world());
+        assert!(velocity.0.x < 0., "the ball should have been returned");
+        assert!(
+            velocity.0.y > 0.,
+            "the return should carry the paddle's motion"
+        );
+    }
+
+    #[test]
+    fn ball_overlapping_a_paddle_gets_back_out() {
+        // as when a paddle moves down onto the ball, which is still
+        // rising into the paddle's bottom end
+        let mut app = headless_app(vec![]);
+        run_frames(&mut app, 1);
+        let mut balls = app
+            .world_mut()
+            .query_filtered::<(&mut Position, &mut Velocity), With<Ball>>();
+        let (mut position, mut velocity) = balls.single_mut(app.world_mut());
+        position.0 = Vec2::new(-590., -28.);
+        velocity.0 = Vec2::new(0., 2.);
+        run_frames(&mut app, 10);
+
+        let mut balls = app.world_mut().query_filtered::<&Position, With<Ball>>();
+        assert!(
+            balls.single(app.world()).0.y < -35.,
+            "the ball should have left the paddle"
+        );
+    }
+
+    #[test]
+    fn ball_leaving_a_paddle_corner_is_let_go() {
+        // grazing the top right corner of the player paddle on its way
+        // past, already bounced up off it and still touching
+        let mut app = headless_app(vec![]);
+        run_frames(&mut app, 1);
+        let mut balls = app
+            .world_mut()
+            .query_filtered::<(&mut Position, &mut Velocity), With<Ball>>();
+        let (mut position, mut velocity) = balls.single_mut(app.world_mut());
+        position.0 = Vec2::new(-583.7, 29.83);
+        velocity.0 = Vec2::new(-8., 1.);
+        run_frames(&mut app, 3);
+
+        let mut balls = app.world_mut().query_filtered::<&Position, With<Ball>>();
+        assert!(
+            balls.single(app.world()).0.x < -600.,
+            "the ball should have carried on over the paddle"
+        );
+    }
+
+    #[test]
+    fn paddle_edge_returns_at_an_angle() {
+        // straight at the player paddle, two thirds of the way up it
+        // counting the ball's radius
+        let mut app = headless_app(vec![]);
+        run_frames(&mut app, 1);
+        let mut balls = app
+            .world_mut()
+            .query_filtered::<(&mut Position, &mut Velocity), With<Ball>>();
+        let (mut position, mut velocity) = balls.single_mut(app.world_mut());
+        position.0 = Vec2::new(-520., 20.);
+        velocity.0 = Vec2::new(-5., 0.);
+        run_frames(&mut app, 20);
+
+        let mut balls = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let velocity = balls.single(app.world()).0;
+        assert!(velocity.x > 0., "the ball should be returned");
+        let angle = velocity.y.atan2(velocity.x).to_degrees();
+        assert!((angle - 50. * 2. / 3.).abs() < 0.01, "left at {angle}°");
+        assert!((velocity.length() - 5.).abs() < 1e-4);
+    }
+}
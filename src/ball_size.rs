@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use bevy::sprite::Mesh2dHandle;
+
+use crate::game_state::{AddGameMode, GameState};
+use crate::{handle_collisions, Ball, BallAssets, HitCount, Shape, BALL_RADIUS};
+
+const HITS_PER_GROWTH: u32 = 3;
+const GROWTH_PER_STEP: f32 = 1.;
+const MAX_BALL_RADIUS: f32 = BALL_RADIUS * 4.;
+
+// Game variant where the ball grows as the rally goes on.
+// Served balls always start at `BALL_RADIUS` again.
+#[derive(Resource, Default)]
+struct BallSizeChallenge(bool);
+
+pub struct BallSizeChallengePlugin;
+
+impl Plugin for BallSizeChallengePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BallSizeChallenge>()
+            .add_game_mode(
+                KeyCode::Digit2,
+                "mode_ball_size",
+                toggle_ball_size_challenge,
+            )
+            .add_systems(
+                FixedUpdate,
+                grow_balls
+                    .after(handle_collisions)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn toggle_ball_size_challenge(mut challenge: ResMut<BallSizeChallenge>) {
+    challenge.0 = !challenge.0;
+    info!("Ball size challenge: {}", challenge.0);
+}
+
+fn grow_balls(
+    challenge: Res<BallSizeChallenge>,
+    ball_assets: Res<BallAssets>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut balls: Query<(&HitCount, &mut Shape, &mut Mesh2dHandle), (With<Ball>, Changed<HitCount>)>,
+) {
+    if !challenge.0 {
+        return;
+    }
+
+    for (hit_count, mut shape, mut mesh) in &mut balls {
+        if hit_count.0 == 0 || hit_count.0 % HITS_PER_GROWTH != 0 {
+            continue;
+        }
+
+        // the collision circle is built from the shape, so this is all
+        // it takes for the bigger ball to be hit as a bigger ball
+        let radius = (shape.0.x + GROWTH_PER_STEP).min(MAX_BALL_RADIUS);
+        shape.0 = Vec2::splat(radius);
+
+        // every ball shares one mesh until it first grows,
+        // so other balls don't grow along with it
+        if mesh.0 == ball_assets.mesh {
+            mesh.0 = meshes.add(Circle::new(radius));
+        } else if let Some(circle) = meshes.get_mut(&mesh.0) {
+            *circle = Circle::new(radius).into();
+        }
+    }
+}
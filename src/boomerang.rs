@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+
+use crate::game_state::{AddGameMode, GameState};
+use crate::{handle_collisions, Ball, Position, Velocity, MAX_BALL_SPEED};
+
+const PULL_STRENGTH: f32 = 0.05;
+// the most a single step of pull can change the velocity by,
+// enough to bend the ball's path without reversing a return
+const MAX_PULL: f32 = 0.2;
+
+// Game variant where the ball curves back towards the middle
+// whenever it strays into the outer quarters of the field
+#[derive(Resource, Default)]
+struct BoomerangMode(bool);
+
+pub struct BoomerangPlugin;
+
+impl Plugin for BoomerangPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BoomerangMode>()
+            .add_game_mode(KeyCode::KeyO, "mode_boomerang", toggle_boomerang_mode)
+            .add_systems(
+                FixedUpdate,
+                pull_to_center
+                    .after(handle_collisions)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn toggle_boomerang_mode(mut boomerang: ResMut<BoomerangMode>) {
+    boomerang.0 = !boomerang.0;
+    info!("Boomerang mode: {}", boomerang.0);
+}
+
+fn pull_to_center(
+    boomerang: Res<BoomerangMode>,
+    mut balls: Query<(&Position, &mut Velocity), With<Ball>>,
+    window: Query<&Window>,
+) {
+    if !boomerang.0 {
+        return;
+    }
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+
+    for (position, mut velocity) in &mut balls {
+        if position.0.x.abs() <= window.resolution.width() / 4. {
+            continue;
+        }
+
+        let pull = (-position.0 * PULL_STRENGTH).clamp_length_max(MAX_PULL);
+        velocity.0 = (velocity.0 + pull).clamp_length_max(MAX_BALL_SPEED);
+    }
+}
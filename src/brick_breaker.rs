@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+
+use crate::game_state::{reset_resource, AddGameMode, GameState, InMatch};
+use crate::{
+    break_gutters, handle_collisions, update_score, Health, Position, Score, Scored, Scorer, Shape,
+};
+
+const GRID_SIZE: usize = 5;
+const BLOCK_SIZE: Vec2 = Vec2::new(30., 15.);
+const BLOCK_GAP: f32 = 6.;
+const BLOCK_HEALTH: u32 = 3;
+// every this many blocks cleared adds one to the multiplier
+const BLOCKS_PER_MULTIPLIER: u32 = 10;
+
+// Game variant with a grid of breakable blocks in the middle.
+// Blocks wear down through the same `Health` as the gutters.
+#[derive(Resource, Default)]
+struct BrickBreakerMode(bool);
+
+#[derive(Resource, Default)]
+struct BlocksCleared(u32);
+
+impl BlocksCleared {
+    fn multiplier(&self) -> u32 {
+        1 + self.0 / BLOCKS_PER_MULTIPLIER
+    }
+}
+
+#[derive(Component)]
+struct Block;
+
+// One material per health level, from red at 1 up to green at 3
+#[derive(Resource)]
+struct BlockMaterials([Handle<ColorMaterial>; BLOCK_HEALTH as usize]);
+
+#[derive(Resource)]
+struct BlockMesh(Handle<Mesh>);
+
+pub struct BrickBreakerPlugin;
+
+impl Plugin for BrickBreakerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BrickBreakerMode>()
+            .init_resource::<BlocksCleared>()
+            .add_systems(Startup, load_block_assets)
+            .add_systems(OnEnter(InMatch), reset_resource::<BlocksCleared>)
+            .add_game_mode(KeyCode::KeyI, "mode_brick_breaker", toggle_brick_breaker)
+            .add_systems(
+                FixedUpdate,
+                (
+                    break_blocks.after(handle_collisions).after(break_gutters),
+                    color_blocks.after(handle_collisions),
+                    respawn_grid.after(break_blocks),
+                    multiply_score.after(update_score),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn load_block_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.insert_resource(BlockMesh(meshes.add(Rectangle::from_size(BLOCK_SIZE))));
+    commands.insert_resource(BlockMaterials(
+        [
+            Color::srgb(0.9, 0.2, 0.2),
+            Color::srgb(0.95, 0.85, 0.2),
+            Color::srgb(0.3, 0.8, 0.3),
+        ]
+        .map(|color| materials.add(ColorMaterial::from_color(color))),
+    ));
+}
+
+fn spawn_grid(commands: &mut Commands, mesh: &BlockMesh, materials: &BlockMaterials) {
+    let step = BLOCK_SIZE + Vec2::splat(BLOCK_GAP);
+    let center = (GRID_SIZE / 2) as f32;
+
+    for row in 0..GRID_SIZE {
+        for column in 0..GRID_SIZE {
+            // the middle is left open for the ball to be served from
+            if row == GRID_SIZE / 2 && column == GRID_SIZE / 2 {
+                continue;
+            }
+
+            let position = (Vec2::new(column as f32, row as f32) - center) * step;
+            commands.spawn((
+                Block,
+                Health(BLOCK_HEALTH),
+                Position(position),
+                Shape(BLOCK_SIZE),
+                MaterialMesh2dBundle {
+                    mesh: mesh.0.clone().into(),
+                    material: materials.0[BLOCK_HEALTH as usize - 1].clone(),
+                    transform: Transform::from_translation(position.extend(0.)),
+                    ..default()
+                },
+            ));
+        }
+    }
+}
+
+fn toggle_brick_breaker(
+    mut commands: Commands,
+    mut mode: ResMut<BrickBreakerMode>,
+    mut cleared: ResMut<BlocksCleared>,
+    blocks: Query<Entity, With<Block>>,
+    mesh: Res<BlockMesh>,
+    materials: Res<BlockMaterials>,
+) {
+    mode.0 = !mode.0;
+    info!("Brick breaker: {}", mode.0);
+
+    cleared.0 = 0;
+    for entity in &blocks {
+        commands.entity(entity).despawn();
+    }
+    if mode.0 {
+        spawn_grid(&mut commands, &mesh, &materials);
+    }
+}
+
+fn break_blocks(
+    mut commands: Commands,
+    mut cleared: ResMut<BlocksCleared>,
+    blocks: Query<(Entity, &Health), With<Block>>,
+) {
+    for (entity, health) in &blocks {
+        if health.0 == 0 {
+            commands.entity(entity).despawn();
+            cleared.0 += 1;
+            info!(
+                "Blocks cleared: {}, multiplier ×{}",
+                cleared.0,
+                cleared.multiplier()
+            );
+        }
+    }
+}
+
+fn color_blocks(
+    materials: Res<BlockMaterials>,
+    mut blocks: Query<(&Health, &mut Handle<ColorMaterial>), (With<Block>, Changed<Health>)>,
+) {
+    for (health, mut material) in &mut blocks {
+        if health.0 > 0 {
+            *material = materials.0[health.0 as usize - 1].clone();
+        }
+    }
+}
+
+// A fresh grid once the last block of the old one is gone
+fn respawn_grid(
+    mut commands: Commands,
+    mode: Res<BrickBreakerMode>,
+    blocks: Query<&Health, With<Block>>,
+    mesh: Res<BlockMesh>,
+    materials: Res<BlockMaterials>,
+) {
+    if mode.0 && blocks.iter().all(|health| health.0 == 0) {
+        spawn_grid(&mut commands, &mesh, &materials);
+    }
+}
+
+fn multiply_score(
+    mode: Res<BrickBreakerMode>,
+    cleared: Res<BlocksCleared>,
+    mut score: ResMut<Score>,
+    mut scored_events: EventReader<Scored>,
+) {
+    if !mode.0 {
+        scored_events.clear();
+        return;
+    }
+
+    for event in scored_events.read() {
+        let extra = event.1 * (cleared.multiplier() - 1);
+        if extra == 0 {
+            continue;
+        }
+        match event.0 {
+            Scorer::Player => score.player += extra,
+            Scorer::Ai => score.ai += extra,
+        }
+        info!("Block multiplier: +{extra}");
+    }
+}
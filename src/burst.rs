@@ -0,0 +1,193 @@
+use bevy::prelude::*;
+use bevy::sprite::{Anchor, MaterialMesh2dBundle};
+
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::key_bindings::KeyBindings;
+use crate::serve::{aim_serve, ServeSelector};
+use crate::{move_ball, Ball, BallAssets, Player, Position, Shape, Velocity};
+
+const BURST_COOLDOWN_SECS: f32 = 15.;
+const BURST_SECS: f32 = 0.5;
+const BURST_SPEED_FACTOR: f32 = 3.;
+const BURST_BAR_SIZE: Vec2 = Vec2::new(30., 3.);
+// gap between the bottom of the paddle and the bar
+const BURST_BAR_GAP: f32 = 6.;
+
+// Only the player can burst, and only on their own serve
+#[derive(Resource)]
+struct BurstCooldown(Timer);
+
+impl Default for BurstCooldown {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(BURST_COOLDOWN_SECS, TimerMode::Once);
+        // ready from the start
+        timer.tick(timer.duration());
+        BurstCooldown(timer)
+    }
+}
+
+// On a ball launched with a burst, until it slows back down
+#[derive(Component)]
+struct BurstTimer(Timer);
+
+// White cover over a bursting ball. A child rather than a material
+// swap, since colour blind mode resets ball materials every frame.
+#[derive(Component)]
+struct BurstFlash;
+
+#[derive(Resource)]
+struct BurstFlashMaterial(Handle<ColorMaterial>);
+
+#[derive(Component)]
+struct BurstBar;
+
+#[derive(Component)]
+struct BurstBarFill;
+
+pub struct BurstShotPlugin;
+
+impl Plugin for BurstShotPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BurstCooldown>()
+            .add_systems(Startup, (load_burst_flash_material, spawn_burst_bar))
+            .add_systems(OnEnter(InMatch), reset_resource::<BurstCooldown>)
+            // before the serve launches, while the ball is still held
+            .add_systems(
+                Update,
+                (
+                    start_burst
+                        .before(aim_serve)
+                        .run_if(in_state(GameState::Playing)),
+                    update_burst_bar.after(start_burst),
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                end_burst
+                    .after(move_ball)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn load_burst_flash_material(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
+    commands.insert_resource(BurstFlashMaterial(
+        materials.add(ColorMaterial::from_color(Color::WHITE)),
+    ));
+}
+
+fn spawn_burst_bar(mut commands: Commands) {
+    commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(1., 1., 1., 0.2),
+                    custom_size: Some(BURST_BAR_SIZE),
+                    ..default()
+                },
+                ..default()
+            },
+            BurstBar,
+        ))
+        .with_children(|parent| {
+            // grows from the left edge as the cooldown runs down
+            parent.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::WHITE,
+                        custom_size: Some(BURST_BAR_SIZE),
+                        anchor: Anchor::CenterLeft,
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(-BURST_BAR_SIZE.x / 2., 0., 0.1),
+                    ..default()
+                },
+                BurstBarFill,
+            ));
+        });
+}
+
+fn start_burst(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut cooldown: ResMut<BurstCooldown>,
+    selector: Option<Res<ServeSelector>>,
+    ball_assets: Res<BallAssets>,
+    flash_material: Res<BurstFlashMaterial>,
+    mut balls: Query<(Entity, &mut Velocity), With<Ball>>,
+    time: Res<Time>,
+) {
+    cooldown.0.tick(time.delta());
+
+    // without the serve selector the player never has a serve to burst
+    let serve_pending = selector.is_some_and(|selector| selector.serve_pending());
+    if !serve_pending || !keyboard_input.just_pressed(bindings.serve) {
+        return;
+    }
+    if !cooldown.0.finished() {
+        return;
+    }
+
+    for (entity, mut velocity) in &mut balls {
+        // the serve keeps the ball's speed, only changing its direction
+        velocity.0 *= BURST_SPEED_FACTOR;
+        commands
+            .entity(entity)
+            .insert(BurstTimer(Timer::from_seconds(BURST_SECS, TimerMode::Once)))
+            .with_children(|parent| {
+                parent.spawn((
+                    MaterialMesh2dBundle {
+                        mesh: ball_assets.mesh.clone().into(),
+                        material: flash_material.0.clone(),
+                        transform: Transform::from_xyz(0., 0., 0.1),
+                        ..default()
+                    },
+                    BurstFlash,
+                ));
+            });
+    }
+    cooldown.0.reset();
+    info!("Burst shot");
+}
+
+fn end_burst(
+    mut commands: Commands,
+    mut balls: Query<(Entity, &mut BurstTimer, &mut Velocity, &Children), With<Ball>>,
+    flashes: Query<(), With<BurstFlash>>,
+    time: Res<Time>,
+) {
+    for (entity, mut burst, mut velocity, children) in &mut balls {
+        if !burst.0.tick(time.delta()).finished() {
+            continue;
+        }
+
+        velocity.0 /= BURST_SPEED_FACTOR;
+        commands.entity(entity).remove::<BurstTimer>();
+        for &child in children {
+            if flashes.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+    }
+}
+
+fn update_burst_bar(
+    cooldown: Res<BurstCooldown>,
+    player: Query<(&Position, &Shape), With<Player>>,
+    mut bar: Query<&mut Transform, With<BurstBar>>,
+    mut fill: Query<&mut Sprite, With<BurstBarFill>>,
+) {
+    let (Ok((position, shape)), Ok(mut transform)) = (player.get_single(), bar.get_single_mut())
+    else {
+        return;
+    };
+
+    let below = position.0.y - shape.0.y / 2. - BURST_BAR_GAP;
+    transform.translation = Vec3::new(position.0.x, below, 1.);
+
+    if let Ok(mut sprite) = fill.get_single_mut() {
+        let fraction = cooldown.0.fraction();
+        sprite.custom_size = Some(Vec2::new(BURST_BAR_SIZE.x * fraction, BURST_BAR_SIZE.y));
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::Position;
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        // Our projection system runs in `Update`, after every
+        // physics step of the frame, so we are not reading
+        // movement one frame behind
+        app.add_systems(Startup, spawn_camera)
+            .add_systems(Update, project_positions);
+    }
+}
+
+fn spawn_camera(mut commands: Commands) {
+    println!("Spawning camera");
+    commands.spawn_empty().insert(Camera2dBundle {
+        // set explicitly as the zoom camera scales it at runtime
+        projection: OrthographicProjection {
+            scale: 1.,
+            ..Camera2dBundle::default().projection
+        },
+        ..default()
+    });
+}
+
+fn project_positions(mut positionables: Query<(&mut Transform, &Position)>) {
+    // Our position is `Vec2` but a translation is `Vec3`
+    // so we extend our `Vec2` into one by adding a `z`
+    // value of 0
+
+    for (mut transform, position) in &mut positionables {
+        transform.translation = position.0.extend(0.);
+    }
+}
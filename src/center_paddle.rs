@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+
+use crate::game_state::{AddGameMode, GameState};
+use crate::{detect_collisions, PaddleAssets, Position, Shape, PADDLE_HEIGHT, PADDLE_WIDTH};
+
+const CENTER_PADDLE_SCALE: f32 = 0.5;
+const CENTER_PADDLE_AMPLITUDE: f32 = 150.;
+// radians per second
+const CENTER_PADDLE_FREQUENCY: f32 = 0.8;
+
+// Game variant with a small paddle in the middle of the field that
+// nobody controls. It has no `Paddle` marker, so bounces off it never
+// count as anyone's hit.
+#[derive(Resource, Default)]
+struct ObstaclePaddleMode(bool);
+
+#[derive(Component)]
+struct CenterPaddle;
+
+#[derive(Resource)]
+struct CenterPaddleMaterial(Handle<ColorMaterial>);
+
+pub struct ObstaclePaddlePlugin;
+
+impl Plugin for ObstaclePaddlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ObstaclePaddleMode>()
+            .add_systems(Startup, load_center_paddle_material)
+            .add_game_mode(
+                KeyCode::KeyE,
+                "mode_obstacle_paddle",
+                toggle_obstacle_paddle,
+            )
+            .add_systems(
+                FixedUpdate,
+                oscillate_center_paddle
+                    .before(detect_collisions)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn load_center_paddle_material(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.insert_resource(CenterPaddleMaterial(
+        materials.add(ColorMaterial::from_color(Color::srgb(0.6, 0.6, 0.6))),
+    ));
+}
+
+fn toggle_obstacle_paddle(
+    mut commands: Commands,
+    mut mode: ResMut<ObstaclePaddleMode>,
+    center_paddles: Query<Entity, With<CenterPaddle>>,
+    paddle_assets: Option<Res<PaddleAssets>>,
+    material: Res<CenterPaddleMaterial>,
+) {
+    let Some(paddle_assets) = paddle_assets else {
+        return;
+    };
+    mode.0 = !mode.0;
+    info!("Obstacle paddle: {}", mode.0);
+
+    if !mode.0 {
+        for entity in &center_paddles {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    commands.spawn((
+        CenterPaddle,
+        Position(Vec2::ZERO),
+        Shape(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT) * CENTER_PADDLE_SCALE),
+        MaterialMesh2dBundle {
+            material: material.0.clone(),
+            // the paddle mesh is full size, so scale it to the shape
+            transform: Transform::from_scale(Vec3::new(
+                CENTER_PADDLE_SCALE,
+                CENTER_PADDLE_SCALE,
+                1.,
+            )),
+            ..paddle_assets.bundle()
+        },
+    ));
+}
+
+fn oscillate_center_paddle(
+    mut center_paddles: Query<&mut Position, With<CenterPaddle>>,
+    time: Res<Time>,
+) {
+    for mut position in &mut center_paddles {
+        position.0.y =
+            CENTER_PADDLE_AMPLITUDE * (time.elapsed_seconds() * CENTER_PADDLE_FREQUENCY).sin();
+    }
+}
@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+
+use crate::game_state::{reset_resource, AddGameMode, GameState, InMatch};
+use crate::{
+    detect_scoring, move_ball, random_serve_velocity, Ball, HitCount, LastHitBy, Position, Score,
+    Scorer, Velocity,
+};
+
+const ZONE_WIDTH: f32 = 20.;
+const ZONE_HEIGHT: f32 = 60.;
+// as fractions of the window height, from the middle
+const ZONE_OFFSETS: [f32; 3] = [-0.3, 0., 0.3];
+
+// Game variant where only shots through a scoring zone count
+#[derive(Resource, Default)]
+struct ChallengeMode(bool);
+
+// Each miss costs half a point. `Score` only holds whole points,
+// so halves are banked here until they add up to one.
+#[derive(Resource, Default)]
+struct MissedHalfPoints {
+    player: u32,
+    ai: u32,
+}
+
+#[derive(Component)]
+struct ScoringZone {
+    half_height: f32,
+}
+
+pub struct ChallengeModePlugin;
+
+impl Plugin for ChallengeModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChallengeMode>()
+            .init_resource::<MissedHalfPoints>()
+            .add_systems(OnEnter(InMatch), reset_resource::<MissedHalfPoints>)
+            .add_game_mode(KeyCode::KeyC, "mode_challenge", toggle_challenge_mode)
+            // a missed shot is put back in play before it can be scored
+            .add_systems(
+                FixedUpdate,
+                check_scoring_zones
+                    .after(move_ball)
+                    .before(detect_scoring)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn toggle_challenge_mode(
+    mut commands: Commands,
+    mut challenge: ResMut<ChallengeMode>,
+    zones: Query<Entity, With<ScoringZone>>,
+    window: Query<&Window>,
+) {
+    challenge.0 = !challenge.0;
+    info!("Challenge mode: {}", challenge.0);
+
+    for entity in &zones {
+        commands.entity(entity).despawn();
+    }
+    if !challenge.0 {
+        return;
+    }
+
+    if let Ok(window) = window.get_single() {
+        let window_width = window.resolution.width();
+        let window_height = window.resolution.height();
+
+        for x in [-window_width / 2., window_width / 2.] {
+            for offset in ZONE_OFFSETS {
+                let y = offset * window_height;
+                commands.spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::srgba(0.2, 0.9, 0.3, 0.3),
+                            custom_size: Some(Vec2::new(ZONE_WIDTH, ZONE_HEIGHT)),
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(x, y, 1.),
+                        ..default()
+                    },
+                    Position(Vec2::new(x, y)),
+                    ScoringZone {
+                        half_height: ZONE_HEIGHT / 2.,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+fn check_scoring_zones(
+    challenge: Res<ChallengeMode>,
+    mut score: ResMut<Score>,
+    mut missed: ResMut<MissedHalfPoints>,
+    mut balls: Query<(&mut Position, &mut Velocity, &mut HitCount, &mut LastHitBy), With<Ball>>,
+    zones: Query<(&Position, &ScoringZone), Without<Ball>>,
+    window: Query<&Window>,
+) {
+    if !challenge.0 {
+        return;
+    }
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    let half_width = window.resolution.width() / 2.;
+
+    for (mut position, mut velocity, mut hit_count, mut last_hit_by) in &mut balls {
+        // same exits as `detect_scoring`
+        let shooter = if position.0.x > half_width {
+            Scorer::Player
+        } else if position.0.x < -half_width {
+            Scorer::Ai
+        } else {
+            continue;
+        };
+
+        let through_zone = zones.iter().any(|(zone_position, zone)| {
+            zone_position.0.x.signum() == position.0.x.signum()
+                && (position.0.y - zone_position.0.y).abs() <= zone.half_height
+        });
+        if through_zone {
+            continue;
+        }
+
+        info!("Missed the scoring zones");
+        let (halves, points, x_dir) = match shooter {
+            Scorer::Player => (&mut missed.player, &mut score.player, -1.),
+            Scorer::Ai => (&mut missed.ai, &mut score.ai, 1.),
+        };
+        *halves += 1;
+        if *halves == 2 {
+            *halves = 0;
+            *points = points.saturating_sub(1);
+        }
+
+        // serve again towards whoever missed
+        position.0 = Vec2::ZERO;
+        velocity.0 = random_serve_velocity(x_dir);
+        hit_count.0 = 0;
+        last_hit_by.0 = None;
+    }
+}
@@ -0,0 +1,202 @@
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::localization::{t, Locale};
+
+// built in, so the changelog can't go missing from an install
+const CHANGELOG: &str = include_str!("../CHANGELOG.md");
+const LINE_SCROLL_PX: f32 = 20.;
+
+enum ChangelogLine<'a> {
+    // the number of `#`s, and the title
+    Heading(usize, &'a str),
+    Item(&'a str),
+    Text(&'a str),
+}
+
+fn parse_changelog(markdown: &str) -> Vec<ChangelogLine<'_>> {
+    markdown
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let level = line.chars().take_while(|c| *c == '#').count();
+            if level > 0 {
+                ChangelogLine::Heading(level, line[level..].trim())
+            } else if let Some(item) = line.strip_prefix("- ").or(line.strip_prefix("* ")) {
+                ChangelogLine::Item(item)
+            } else {
+                ChangelogLine::Text(line)
+            }
+        })
+        .collect()
+}
+
+#[derive(Component)]
+struct ChangelogScreen;
+
+// The list of lines, moved up inside the clipped screen to scroll it
+#[derive(Component, Default)]
+struct ChangelogScroll(f32);
+
+#[derive(Component)]
+struct ChangelogHelp;
+
+pub struct ChangelogPlugin;
+
+impl Plugin for ChangelogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_changelog_screen)
+            .add_systems(
+                Update,
+                (
+                    open_changelog.run_if(input_just_pressed(KeyCode::KeyJ)),
+                    close_changelog.run_if(input_just_pressed(KeyCode::Escape)),
+                    scroll_changelog,
+                    update_changelog_help,
+                ),
+            );
+    }
+}
+
+fn spawn_changelog_screen(mut commands: Commands) {
+    let text = |value: &str, font_size: f32, color: Color| {
+        TextBundle::from_section(
+            value,
+            TextStyle {
+                font_size,
+                color,
+                ..default()
+            },
+        )
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                visibility: Visibility::Hidden,
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(15.),
+                    left: Val::Percent(25.),
+                    width: Val::Percent(50.),
+                    height: Val::Percent(70.),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(12.)),
+                    row_gap: Val::Px(8.),
+                    ..default()
+                },
+                background_color: Color::srgba(0., 0., 0., 0.9).into(),
+                // over the menu and pause screens
+                z_index: ZIndex::Global(95),
+                ..default()
+            },
+            ChangelogScreen,
+        ))
+        .with_children(|screen| {
+            screen.spawn((text("", 16., Color::srgb(0.6, 0.6, 0.6)), ChangelogHelp));
+            screen
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        flex_grow: 1.,
+                        overflow: Overflow::clip_y(),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|viewport| {
+                    viewport
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(4.),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            ChangelogScroll::default(),
+                        ))
+                        .with_children(|list| {
+                            for line in parse_changelog(CHANGELOG) {
+                                list.spawn(match line {
+                                    ChangelogLine::Heading(level, title) => text(
+                                        title,
+                                        // smaller for deeper headings
+                                        36. - 6. * level as f32,
+                                        Color::srgb(1., 0.84, 0.),
+                                    ),
+                                    ChangelogLine::Item(item) => {
+                                        text(&format!("• {item}"), 18., Color::WHITE)
+                                    }
+                                    ChangelogLine::Text(value) => text(value, 18., Color::WHITE),
+                                });
+                            }
+                        });
+                });
+        });
+}
+
+// reading it mid rally shouldn't cost a point
+fn open_changelog(
+    mut screen: Query<&mut Visibility, With<ChangelogScreen>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if let Ok(mut visibility) = screen.get_single_mut() {
+        *visibility = Visibility::Visible;
+        if *state.get() == GameState::Playing {
+            time.pause();
+            next_state.set(GameState::Paused);
+        }
+    }
+}
+
+// The game stays paused, unless Escape is also the pause key
+fn close_changelog(mut screen: Query<&mut Visibility, With<ChangelogScreen>>) {
+    if let Ok(mut visibility) = screen.get_single_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+fn scroll_changelog(
+    mut mouse_wheel: EventReader<MouseWheel>,
+    screen: Query<&Visibility, With<ChangelogScreen>>,
+    mut lists: Query<(&mut ChangelogScroll, &mut Style, &Node, &Parent)>,
+    viewports: Query<&Node>,
+) {
+    let scrolled: f32 = mouse_wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * LINE_SCROLL_PX,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+    if scrolled == 0. || !matches!(screen.get_single(), Ok(Visibility::Visible)) {
+        return;
+    }
+
+    for (mut scroll, mut style, list, parent) in &mut lists {
+        let Ok(viewport) = viewports.get(parent.get()) else {
+            continue;
+        };
+        // no further than the bottom of the list
+        let max_scroll = (list.size().y - viewport.size().y).max(0.);
+        scroll.0 = (scroll.0 + scrolled).clamp(-max_scroll, 0.);
+        style.top = Val::Px(scroll.0);
+    }
+}
+
+fn update_changelog_help(locale: Res<Locale>, mut help: Query<&mut Text, With<ChangelogHelp>>) {
+    if !locale.is_changed() {
+        return;
+    }
+
+    for mut text in &mut help {
+        text.sections[0].value = t("changelog_help", &locale).to_string();
+    }
+}
@@ -0,0 +1,127 @@
+use std::fs;
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::key_bindings::KeyBindings;
+use crate::localization::{t, Locale};
+use crate::save_state::{load_game, save_game};
+use crate::score::MatchOver;
+use crate::{pause_game, BallFrozen};
+
+const CHECKPOINT_PATH: &str = "checkpoint.ron";
+
+// Open at startup when there is a checkpoint to resume. The ball
+// is held until it's answered.
+#[derive(Resource, Default)]
+struct ResumePrompt(bool);
+
+#[derive(Component)]
+struct ResumePromptText;
+
+pub struct CheckpointPlugin;
+
+impl Plugin for CheckpointPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ResumePrompt>()
+            .add_systems(Startup, open_resume_prompt)
+            .add_systems(
+                Update,
+                (
+                    save_checkpoint.after(pause_game).run_if(paused_by_player),
+                    answer_resume_prompt.run_if(resume_prompt_open),
+                    discard_checkpoint,
+                ),
+            );
+    }
+}
+
+fn paused_by_player(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    time: Res<Time<Virtual>>,
+) -> bool {
+    keyboard_input.just_pressed(bindings.pause) && time.is_paused()
+}
+
+fn resume_prompt_open(prompt: Res<ResumePrompt>) -> bool {
+    prompt.0
+}
+
+fn save_checkpoint(world: &mut World) {
+    save_game(world, CHECKPOINT_PATH);
+}
+
+// a finished match has nothing left to resume
+fn discard_checkpoint(mut match_over: EventReader<MatchOver>) {
+    if match_over.read().last().is_none() || !Path::new(CHECKPOINT_PATH).exists() {
+        return;
+    }
+    if let Err(err) = fs::remove_file(CHECKPOINT_PATH) {
+        error!("Failed to remove {CHECKPOINT_PATH}: {err}");
+    }
+}
+
+fn open_resume_prompt(
+    mut commands: Commands,
+    mut prompt: ResMut<ResumePrompt>,
+    mut ball_frozen: ResMut<BallFrozen>,
+    locale: Res<Locale>,
+) {
+    if !Path::new(CHECKPOINT_PATH).exists() {
+        return;
+    }
+
+    prompt.0 = true;
+    ball_frozen.0 = true;
+    commands.spawn((
+        TextBundle::from_section(
+            t("resume_prompt", &locale),
+            TextStyle {
+                font_size: 28.,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(40.),
+            left: Val::Percent(25.),
+            padding: UiRect::all(Val::Px(10.)),
+            ..default()
+        })
+        .with_background_color(Color::srgba(0., 0., 0., 0.8)),
+        ResumePromptText,
+    ));
+}
+
+// Either way the checkpoint is used up, so an old match
+// isn't offered again on the next launch. Resuming goes straight
+// back into the match, starting over leaves the menu up.
+fn answer_resume_prompt(world: &mut World) {
+    let keyboard_input = world.resource::<ButtonInput<KeyCode>>();
+    let resume = keyboard_input.just_pressed(KeyCode::Enter);
+    if !resume && !keyboard_input.just_pressed(KeyCode::Backspace) {
+        return;
+    }
+
+    if resume {
+        load_game(world, CHECKPOINT_PATH);
+        world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+    }
+    if let Err(err) = fs::remove_file(CHECKPOINT_PATH) {
+        error!("Failed to remove {CHECKPOINT_PATH}: {err}");
+    }
+
+    world.resource_mut::<ResumePrompt>().0 = false;
+    world.resource_mut::<BallFrozen>().0 = false;
+    let mut texts = world.query_filtered::<Entity, With<ResumePromptText>>();
+    let texts: Vec<Entity> = texts.iter(world).collect();
+    for entity in texts {
+        world.despawn(entity);
+    }
+}
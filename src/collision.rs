@@ -0,0 +1,251 @@
+use bevy::math::bounding::{Aabb2d, BoundingCircle, BoundingVolume, IntersectsVolume, RayCast2d};
+use bevy::prelude::*;
+
+// The side of the ball that was hit
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Collision {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Collision {
+    // The way the ball has to go to leave what it hit
+    pub fn way_out(self) -> Vec2 {
+        match self {
+            Collision::Top => Vec2::NEG_Y,
+            Collision::Bottom => Vec2::Y,
+            Collision::Left => Vec2::X,
+            Collision::Right => Vec2::NEG_X,
+        }
+    }
+}
+
+pub fn collide_with_side(ball: BoundingCircle, wall: Aabb2d) -> Option<Collision> {
+    if !ball.intersects(&wall) {
+        return None;
+    }
+
+    let closest_point = wall.closest_point(ball.center());
+    let offset = ball.center() - closest_point;
+
+    let side = if offset.x.abs() > offset.y.abs() {
+        if offset.x > 0. {
+            Collision::Left
+        } else {
+            Collision::Right
+        }
+    } else {
+        if offset.y > 0. {
+            Collision::Bottom
+        } else {
+            Collision::Top
+        }
+    };
+
+    Some(side)
+}
+
+// How `detect_collisions` decides whether and where a ball hit a
+// rectangle. Gets the ball's velocity for the step as well, since a
+// swept test needs to know where the ball came from. Besides the side
+// that was hit it returns where the ball's centre should be put.
+pub trait CollisionBackend: Send + Sync {
+    fn ball_vs_rect(
+        &self,
+        ball: BoundingCircle,
+        velocity: Vec2,
+        rect: Aabb2d,
+    ) -> Option<(Collision, Vec2)>;
+}
+
+// The backend `detect_collisions` uses. Insert a different one
+// to swap how collisions are found.
+#[derive(Resource)]
+pub struct ActiveCollisionBackend(pub Box<dyn CollisionBackend>);
+
+impl Default for ActiveCollisionBackend {
+    fn default() -> Self {
+        ActiveCollisionBackend(Box::new(SweepCollisionBackend))
+    }
+}
+
+pub struct Physics2DPlugin;
+
+impl Plugin for Physics2DPlugin {
+    fn build(&self, app: &mut App) {
+        let backend: Box<dyn CollisionBackend> =
+            if std::env::args().any(|arg| arg == "--overlap-collisions") {
+                Box::new(OverlapCollisionBackend)
+            } else {
+                Box::new(SweepCollisionBackend)
+            };
+        app.insert_resource(ActiveCollisionBackend(backend));
+    }
+}
+
+// Checks for overlap where the ball ended up, so a ball moving
+// further in a step than a paddle is thick can pass through it
+pub struct OverlapCollisionBackend;
+
+impl CollisionBackend for OverlapCollisionBackend {
+    fn ball_vs_rect(
+        &self,
+        ball: BoundingCircle,
+        _velocity: Vec2,
+        rect: Aabb2d,
+    ) -> Option<(Collision, Vec2)> {
+        collide_with_side(ball, rect).map(|side| (side, ball.center()))
+    }
+}
+
+// Sweeps the ball along its path for the step, so fast balls
+// can't tunnel, and moves it back to where it first touched
+pub struct SweepCollisionBackend;
+
+impl CollisionBackend for SweepCollisionBackend {
+    fn ball_vs_rect(
+        &self,
+        ball: BoundingCircle,
+        velocity: Vec2,
+        rect: Aabb2d,
+    ) -> Option<(Collision, Vec2)> {
+        let radius = ball.radius();
+        let start = ball.center() - velocity;
+
+        // already touching at the start of the step, or not moving,
+        // leaves nothing to sweep
+        let Ok(direction) = Dir2::new(velocity) else {
+            return OverlapCollisionBackend.ball_vs_rect(ball, velocity, rect);
+        };
+        if rect.closest_point(start).distance(start) <= radius {
+            return OverlapCollisionBackend.ball_vs_rect(ball, velocity, rect);
+        }
+
+        // A circle against a box is a point against the box grown by
+        // the radius, with its corners rounded off. That shape is two
+        // boxes, one grown sideways and one grown up and down, plus a
+        // circle around each of the corners.
+        let ray = RayCast2d::new(start, direction, velocity.length());
+        let boxes = [
+            Aabb2d {
+                min: rect.min - Vec2::new(radius, 0.),
+                max: rect.max + Vec2::new(radius, 0.),
+            },
+            Aabb2d {
+                min: rect.min - Vec2::new(0., radius),
+                max: rect.max + Vec2::new(0., radius),
+            },
+        ];
+        let corners = [
+            rect.min,
+            Vec2::new(rect.min.x, rect.max.y),
+            Vec2::new(rect.max.x, rect.min.y),
+            rect.max,
+        ];
+        let distance = boxes
+            .iter()
+            .filter_map(|grown| ray.aabb_intersection_at(grown))
+            .chain(corners.into_iter().filter_map(|corner| {
+                ray.circle_intersection_at(&BoundingCircle::new(corner, radius))
+            }))
+            .min_by(f32::total_cmp)?;
+        let contact = start + *direction * distance;
+
+        // named for the side of the ball that was hit, like `collide_with_side`
+        let offset = contact - rect.closest_point(contact);
+        let side = if offset.x.abs() > offset.y.abs() {
+            if offset.x > 0. {
+                Collision::Left
+            } else {
+                Collision::Right
+            }
+        } else if offset.y > 0. {
+            Collision::Bottom
+        } else {
+            Collision::Top
+        };
+        Some((side, contact))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_mode::{headless_app, run_frames};
+    use crate::{Ball, Position, Score, Velocity};
+
+    #[test]
+    fn backends_agree_on_a_slow_ball() {
+        // overlapping the paddle's left face after a small step right
+        let ball = BoundingCircle::new(Vec2::new(-8., 0.), 5.);
+        let velocity = Vec2::new(5., 0.);
+        let paddle = Aabb2d::new(Vec2::ZERO, Vec2::new(5., 25.));
+
+        let (overlap_side, overlap_contact) = OverlapCollisionBackend
+            .ball_vs_rect(ball, velocity, paddle)
+            .unwrap();
+        let (sweep_side, sweep_contact) = SweepCollisionBackend
+            .ball_vs_rect(ball, velocity, paddle)
+            .unwrap();
+
+        assert_eq!(overlap_side, Collision::Right);
+        assert_eq!(sweep_side, Collision::Right);
+        assert_eq!(overlap_contact, ball.center);
+        assert_eq!(sweep_contact, Vec2::new(-10., 0.));
+    }
+
+    #[test]
+    fn sweep_backend_rounds_the_corners() {
+        // passing diagonally by the top right corner, close enough to
+        // clip a square grown box but not the rounded one
+        let ball = BoundingCircle::new(Vec2::new(4., 34.), 5.);
+        let velocity = Vec2::new(-10., 10.);
+        let paddle = Aabb2d::new(Vec2::ZERO, Vec2::new(5., 25.));
+        assert!(SweepCollisionBackend
+            .ball_vs_rect(ball, velocity, paddle)
+            .is_none());
+
+        // heading into the corner it's a hit, on whichever side is nearer
+        let ball = BoundingCircle::new(Vec2::new(7., 27.), 5.);
+        let velocity = Vec2::new(-8., -4.);
+        let (side, contact) = SweepCollisionBackend
+            .ball_vs_rect(ball, velocity, paddle)
+            .unwrap();
+        assert_eq!(side, Collision::Left);
+        assert!(contact.distance(Vec2::new(9., 28.)) < 1e-4);
+    }
+
+    #[test]
+    fn sweep_backend_stops_a_fast_ball() {
+        // one step takes the ball from just short of the AI paddle to
+        // past it, so only a swept test sees the hit
+        let fast_ball = |app: &mut App| {
+            // the ball is spawned on the first update
+            run_frames(app, 1);
+            let mut balls = app
+                .world_mut()
+                .query_filtered::<(&mut Position, &mut Velocity), With<Ball>>();
+            let (mut position, mut velocity) = balls.single_mut(app.world_mut());
+            position.0 = Vec2::new(10., 0.);
+            velocity.0 = Vec2::new(40., 0.);
+        };
+
+        let mut app = headless_app(vec![]);
+        app.insert_resource(ActiveCollisionBackend(Box::new(OverlapCollisionBackend)));
+        fast_ball(&mut app);
+        run_frames(&mut app, 20);
+        let score = app.world().resource::<Score>();
+        assert_eq!((score.player, score.ai), (1, 0), "the ball should tunnel");
+
+        // sweeping is the default
+        let mut app = headless_app(vec![]);
+        fast_ball(&mut app);
+        run_frames(&mut app, 20);
+        let score = app.world().resource::<Score>();
+        assert_eq!((score.player, score.ai), (0, 0));
+        let mut balls = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        assert!(balls.single(app.world()).0.x < 0., "the ball should bounce");
+    }
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+
+use crate::{BallCollision, Velocity, MAX_BALL_SPEED, MIN_BALL_SPEED};
+
+const MIN_PITCH: f32 = 0.8;
+const MAX_PITCH: f32 = 1.5;
+
+#[derive(Resource)]
+struct BounceSound(Handle<AudioSource>);
+
+pub struct CollisionAudioPlugin;
+
+impl Plugin for CollisionAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_bounce_sound)
+            .add_systems(Update, play_bounce_sound);
+    }
+}
+
+fn load_bounce_sound(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(BounceSound(asset_server.load("sounds/bounce.ogg")));
+}
+
+// faster balls give a sharper, higher "thwack",
+// slower ones a deeper "thud"
+fn bounce_pitch(ball_speed: f32) -> f32 {
+    let pitch = 1.0 + (ball_speed - MIN_BALL_SPEED) / (MAX_BALL_SPEED - MIN_BALL_SPEED) * 0.5;
+    pitch.clamp(MIN_PITCH, MAX_PITCH)
+}
+
+fn play_bounce_sound(
+    mut commands: Commands,
+    sound: Res<BounceSound>,
+    mut collisions: EventReader<BallCollision>,
+    balls: Query<&Velocity>,
+) {
+    for collision in collisions.read() {
+        // the ball may be gone already, if the bounce ended the rally
+        let Ok(velocity) = balls.get(collision.ball) else {
+            continue;
+        };
+        commands.spawn(AudioBundle {
+            source: sound.0.clone(),
+            settings: PlaybackSettings::DESPAWN.with_speed(bounce_pitch(velocity.0.length())),
+        });
+    }
+}
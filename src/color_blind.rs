@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::render::texture::ImageSampler;
+use bevy::sprite::Mesh2dHandle;
+
+use crate::{settings, Ai, Ball, BallAssets, PaddleAssets, Player, PADDLE_HEIGHT, PADDLE_WIDTH};
+
+const STRIPES: u32 = 4;
+
+// Gives the ball and paddles distinct shapes on top of their colours
+#[derive(Resource)]
+struct ColorBlindMode(bool);
+
+#[derive(Resource)]
+struct ColorBlindAssets {
+    player_paddle_mesh: Handle<Mesh>,
+    ai_paddle_mesh: Handle<Mesh>,
+    striped_ball_material: Handle<ColorMaterial>,
+}
+
+pub struct ColorBlindPlugin;
+
+impl Plugin for ColorBlindPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ColorBlindMode(settings::load().color_blind_mode))
+            .add_systems(Startup, load_color_blind_assets)
+            .add_systems(
+                Update,
+                (toggle_color_blind_mode, apply_color_blind_mode).chain(),
+            );
+    }
+}
+
+// A rectangle the size of the paddle with a bar across its middle
+fn cross_mesh() -> Mesh {
+    let (half_width, half_height) = (PADDLE_WIDTH / 2., PADDLE_HEIGHT / 2.);
+    let (bar_half_width, bar_half_height) = (PADDLE_WIDTH * 1.1, PADDLE_WIDTH * 0.3);
+
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+    for (w, h) in [(half_width, half_height), (bar_half_width, bar_half_height)] {
+        let start = positions.len() as u32;
+        positions.extend([[-w, -h, 0.], [w, -h, 0.], [w, h, 0.], [-w, h, 0.]]);
+        indices.extend([start, start + 1, start + 2, start, start + 2, start + 3]);
+    }
+    let normals = vec![[0., 0., 1.]; positions.len()];
+    let uvs = vec![[0., 0.]; positions.len()];
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    .with_inserted_indices(Indices::U32(indices))
+}
+
+// Alternating light and dark bands, tinted by the material colour
+fn stripe_image() -> Image {
+    let data = (0..STRIPES * 2)
+        .flat_map(|row| {
+            let value = if row % 2 == 0 { 255 } else { 110 };
+            [value, value, value, 255]
+        })
+        .collect();
+
+    let mut image = Image::new(
+        Extent3d {
+            width: 1,
+            height: STRIPES * 2,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    // hard edges between the stripes
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+fn load_color_blind_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    commands.insert_resource(ColorBlindAssets {
+        // rounded ends, still the paddle's full height overall
+        player_paddle_mesh: meshes.add(Capsule2d::new(
+            PADDLE_WIDTH / 2.,
+            PADDLE_HEIGHT - PADDLE_WIDTH,
+        )),
+        ai_paddle_mesh: meshes.add(cross_mesh()),
+        striped_ball_material: materials.add(ColorMaterial {
+            color: Color::srgb_u8(50, 100, 200),
+            texture: Some(images.add(stripe_image())),
+        }),
+    });
+}
+
+// There is no settings screen yet, so B toggles the mode
+fn toggle_color_blind_mode(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut mode: ResMut<ColorBlindMode>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyB) {
+        mode.0 = !mode.0;
+        let saved = mode.0;
+        settings::update(|settings| settings.color_blind_mode = saved);
+        info!("Color blind mode: {}", mode.0);
+    }
+}
+
+// Checked every frame, since served and quickloaded entities
+// come with the normal look from `BallAssets` and `PaddleAssets`
+fn apply_color_blind_mode(
+    mode: Res<ColorBlindMode>,
+    color_blind_assets: Res<ColorBlindAssets>,
+    ball_assets: Res<BallAssets>,
+    paddle_assets: Res<PaddleAssets>,
+    mut paddles: Query<(&mut Mesh2dHandle, Has<Player>), Or<(With<Player>, With<Ai>)>>,
+    mut balls: Query<&mut Handle<ColorMaterial>, With<Ball>>,
+) {
+    for (mut mesh, is_player) in &mut paddles {
+        let wanted = match (mode.0, is_player) {
+            (false, true) => &paddle_assets.player_mesh,
+            (false, false) => &paddle_assets.mesh,
+            (true, true) => &color_blind_assets.player_paddle_mesh,
+            (true, false) => &color_blind_assets.ai_paddle_mesh,
+        };
+        if mesh.0 != *wanted {
+            mesh.0 = wanted.clone();
+        }
+    }
+
+    let wanted = if mode.0 {
+        &color_blind_assets.striped_ball_material
+    } else {
+        &ball_assets.material
+    };
+    for mut material in &mut balls {
+        if *material != *wanted {
+            *material = wanted.clone();
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use rand::random;
+
+use crate::score::{MatchOver, Scorer};
+
+const CONFETTI_PIECES: usize = 80;
+const CONFETTI_SIZE: Vec2 = Vec2::new(8., 4.);
+// px/s², pulling every piece down the screen
+const GRAVITY: f32 = 200.;
+const CONFETTI_SECS: f32 = 3.;
+// px/s either way, so the pieces drift apart as they fall
+const MAX_DRIFT_SPEED: f32 = 80.;
+// radians per second, at most
+const MAX_SPIN_SPEED: f32 = 3.;
+
+// A piece of confetti thrown when the player wins a match. Only
+// ever moved through its `Transform`, it doesn't take part in play.
+#[derive(Component)]
+struct Confetti {
+    velocity: Vec2,
+    spin: f32,
+}
+
+// Seconds left before it's despawned
+#[derive(Component)]
+struct Lifetime(f32);
+
+pub struct ConfettiPlugin;
+
+impl Plugin for ConfettiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (throw_confetti, tick_confetti));
+    }
+}
+
+fn throw_confetti(
+    mut commands: Commands,
+    mut match_over: EventReader<MatchOver>,
+    window: Query<&Window>,
+) {
+    if !match_over
+        .read()
+        .any(|event| matches!(event.0, Scorer::Player))
+    {
+        return;
+    }
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+
+    let width = window.resolution.width();
+    let top = window.resolution.height() / 2.;
+    for _ in 0..CONFETTI_PIECES {
+        let x = (random::<f32>() - 0.5) * width;
+        // staggered above the top edge, so they don't all land at once
+        let y = top + random::<f32>() * top / 2.;
+        commands.spawn((
+            Confetti {
+                velocity: Vec2::new((random::<f32>() * 2. - 1.) * MAX_DRIFT_SPEED, 0.),
+                spin: (random::<f32>() * 2. - 1.) * MAX_SPIN_SPEED,
+            },
+            Lifetime(CONFETTI_SECS),
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::hsl(random::<f32>() * 360., 0.9, 0.6),
+                    custom_size: Some(CONFETTI_SIZE),
+                    ..default()
+                },
+                // over the field, the paddles and the ball
+                transform: Transform::from_xyz(x, y, 5.)
+                    .with_rotation(Quat::from_rotation_z(random::<f32>() * 6.)),
+                ..default()
+            },
+        ));
+    }
+}
+
+fn tick_confetti(
+    mut commands: Commands,
+    mut confetti: Query<(Entity, &mut Confetti, &mut Lifetime, &mut Transform)>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut piece, mut lifetime, mut transform) in &mut confetti {
+        lifetime.0 -= dt;
+        if lifetime.0 <= 0. {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // exact for constant acceleration, whatever the frame time
+        transform.translation.x += piece.velocity.x * dt;
+        transform.translation.y += piece.velocity.y * dt - GRAVITY * dt * dt / 2.;
+        piece.velocity.y -= GRAVITY * dt;
+        transform.rotate_z(piece.spin * dt);
+    }
+}
@@ -0,0 +1,178 @@
+use std::path::PathBuf;
+
+use rusqlite::{params, Connection};
+
+const DB_FILE: &str = "match_history.db";
+
+// Applied in order, each exactly once. A database is at the version
+// of the last one applied, so new ones only ever go on the end.
+const MIGRATIONS: &[&str] = &["CREATE TABLE matches (
+        id INTEGER PRIMARY KEY,
+        timestamp INTEGER NOT NULL,
+        player_score INTEGER NOT NULL,
+        ai_score INTEGER NOT NULL,
+        duration_secs REAL NOT NULL,
+        difficulty TEXT NOT NULL,
+        longest_rally INTEGER NOT NULL,
+        paddle_hits INTEGER NOT NULL
+    )"];
+
+// One finished match, as kept in the `matches` table
+pub struct MatchRecord {
+    // seconds since the Unix epoch
+    pub timestamp: i64,
+    pub player_score: u32,
+    pub ai_score: u32,
+    pub duration_secs: f32,
+    pub difficulty: &'static str,
+    pub longest_rally: u32,
+    pub paddle_hits: u32,
+}
+
+// Every match played, summed up for the stats screen
+#[derive(Debug, PartialEq)]
+pub struct HistorySummary {
+    pub matches: u32,
+    pub wins: u32,
+    // hits per rally, across every point of every match
+    pub rally_avg: f32,
+}
+
+// In the platform's data directory, or next to the game without one
+fn db_path() -> PathBuf {
+    match dirs::data_dir() {
+        Some(dir) => dir.join("learning_bevy").join(DB_FILE),
+        None => PathBuf::from(DB_FILE),
+    }
+}
+
+pub fn open() -> rusqlite::Result<Connection> {
+    let path = db_path();
+    if let Some(dir) = path.parent() {
+        // `open` reports it if the directory still isn't there
+        let _ = std::fs::create_dir_all(dir);
+    }
+    let mut connection = Connection::open(path)?;
+    migrate(&mut connection)?;
+    Ok(connection)
+}
+
+pub fn migrate(connection: &mut Connection) -> rusqlite::Result<()> {
+    connection.execute(
+        "CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL)",
+        [],
+    )?;
+    let version: usize = connection.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_version",
+        [],
+        |row| row.get(0),
+    )?;
+
+    for (applied, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        // a migration and its version bump land together or not at all
+        let transaction = connection.transaction()?;
+        transaction.execute_batch(migration)?;
+        transaction.execute("DELETE FROM schema_version", [])?;
+        transaction.execute(
+            "INSERT INTO schema_version (version) VALUES (?1)",
+            params![applied + 1],
+        )?;
+        transaction.commit()?;
+    }
+    Ok(())
+}
+
+pub fn insert_match(connection: &Connection, record: &MatchRecord) -> rusqlite::Result<()> {
+    connection.execute(
+        "INSERT INTO matches (timestamp, player_score, ai_score, duration_secs,
+            difficulty, longest_rally, paddle_hits)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            record.timestamp,
+            record.player_score,
+            record.ai_score,
+            record.duration_secs,
+            record.difficulty,
+            record.longest_rally,
+            record.paddle_hits,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn summary(connection: &Connection) -> rusqlite::Result<HistorySummary> {
+    connection.query_row(
+        "SELECT COUNT(*),
+                COALESCE(SUM(player_score > ai_score), 0),
+                COALESCE(SUM(paddle_hits), 0),
+                COALESCE(SUM(player_score + ai_score), 0)
+         FROM matches",
+        [],
+        |row| {
+            // every point scored ends a rally
+            let (hits, rallies): (u32, u32) = (row.get(2)?, row.get(3)?);
+            Ok(HistorySummary {
+                matches: row.get(0)?,
+                wins: row.get(1)?,
+                rally_avg: if rallies == 0 {
+                    0.
+                } else {
+                    hits as f32 / rallies as f32
+                },
+            })
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(player_score: u32, ai_score: u32, paddle_hits: u32) -> MatchRecord {
+        MatchRecord {
+            timestamp: 0,
+            player_score,
+            ai_score,
+            duration_secs: 60.,
+            difficulty: "medium",
+            longest_rally: 3,
+            paddle_hits,
+        }
+    }
+
+    #[test]
+    fn migrations_only_apply_once() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        migrate(&mut connection).unwrap();
+        insert_match(&connection, &record(11, 3, 28)).unwrap();
+        // as on every start after the first
+        migrate(&mut connection).unwrap();
+
+        assert_eq!(summary(&connection).unwrap().matches, 1);
+    }
+
+    #[test]
+    fn summary_counts_wins_and_hits_per_rally() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        migrate(&mut connection).unwrap();
+        assert_eq!(
+            summary(&connection).unwrap(),
+            HistorySummary {
+                matches: 0,
+                wins: 0,
+                rally_avg: 0.,
+            }
+        );
+
+        insert_match(&connection, &record(11, 9, 60)).unwrap();
+        insert_match(&connection, &record(2, 11, 0)).unwrap();
+        assert_eq!(
+            summary(&connection).unwrap(),
+            HistorySummary {
+                matches: 2,
+                wins: 1,
+                rally_avg: 60. / 33.,
+            }
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use bevy::diagnostic::{DiagnosticsStore, EntityCountDiagnosticsPlugin};
+use bevy::prelude::*;
+
+use crate::simulation_speed::SimulationSpeed;
+
+// more entities than this usually means a leaking effect
+const ENTITY_COUNT_WARNING: f64 = 500.;
+
+#[derive(Component)]
+struct DebugOverlayText;
+
+pub struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(EntityCountDiagnosticsPlugin)
+            .add_systems(Startup, spawn_debug_overlay)
+            .add_systems(
+                Update,
+                (log_entity_count, toggle_debug_overlay, update_debug_overlay),
+            );
+    }
+}
+
+fn entity_count(diagnostics: &DiagnosticsStore) -> Option<f64> {
+    diagnostics
+        .get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+        .and_then(|diagnostic| diagnostic.value())
+}
+
+// warns once each time the count crosses the limit, not every frame
+fn log_entity_count(diagnostics: Res<DiagnosticsStore>, mut over_limit: Local<bool>) {
+    let Some(count) = entity_count(&diagnostics) else {
+        return;
+    };
+
+    if count > ENTITY_COUNT_WARNING && !*over_limit {
+        warn!("{count} entities in the world, something may be leaking");
+    }
+    *over_limit = count > ENTITY_COUNT_WARNING;
+}
+
+fn spawn_debug_overlay(mut commands: Commands) {
+    commands.spawn((
+        TextBundle {
+            visibility: Visibility::Hidden,
+            ..TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 16.,
+                    color: Color::srgb_u8(0, 255, 0),
+                    ..default()
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                ..default()
+            })
+        },
+        DebugOverlayText,
+    ));
+}
+
+fn toggle_debug_overlay(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut overlay: Query<&mut Visibility, With<DebugOverlayText>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F3) {
+        if let Ok(mut visibility) = overlay.get_single_mut() {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Visible,
+                _ => Visibility::Hidden,
+            };
+        }
+    }
+}
+
+fn update_debug_overlay(
+    diagnostics: Res<DiagnosticsStore>,
+    simulation_speed: Res<SimulationSpeed>,
+    mut overlay: Query<&mut Text, With<DebugOverlayText>>,
+) {
+    if let (Some(count), Ok(mut text)) = (entity_count(&diagnostics), overlay.get_single_mut()) {
+        text.sections[0].value = format!("Entities: {count}\nSpeed: ×{}", simulation_speed.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_mode::{headless_app, run_frames};
+    use crate::trail::BallTrailPlugin;
+
+    #[test]
+    fn entity_count_stays_bounded() {
+        let mut app = headless_app(vec![]);
+        // the trail draws particles every step, so play with it on
+        app.add_plugins(BallTrailPlugin);
+        run_frames(&mut app, 1);
+        let entity_count = app.world().entities().len();
+        run_frames(&mut app, 60);
+
+        assert_eq!(app.world().entities().len(), entity_count);
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::powerups::{PowerupCollected, PowerupKind};
+use crate::{move_paddles, Paddle, Position, PADDLE_PADDING};
+
+const WIDE_SCALE: f32 = 2.;
+const WIDE_SECS: f32 = 8.;
+// fraction of the gap to the target scale closed per second
+const SCALE_SPEED: f32 = 3.;
+// close enough to snap onto the target
+const SCALE_EPSILON: f32 = 0.001;
+
+// How much wider than the window the field currently is. The camera
+// zooms out by the same amount, and the paddles and the scoring lines
+// move out with it. The gutters stay put.
+#[derive(Resource)]
+pub struct PlayfieldScaleAnimation {
+    pub scale: f32,
+    target: f32,
+    timer: Timer,
+}
+
+impl Default for PlayfieldScaleAnimation {
+    fn default() -> Self {
+        PlayfieldScaleAnimation {
+            scale: 1.,
+            target: 1.,
+            timer: Timer::from_seconds(WIDE_SECS, TimerMode::Once),
+        }
+    }
+}
+
+pub struct DoubleWidthPlugin;
+
+impl Plugin for DoubleWidthPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayfieldScaleAnimation>()
+            .add_systems(Update, (widen_playfield, animate_playfield_scale).chain())
+            .add_systems(
+                FixedUpdate,
+                place_paddles
+                    .after(move_paddles)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn widen_playfield(
+    mut collected: EventReader<PowerupCollected>,
+    mut playfield: ResMut<PlayfieldScaleAnimation>,
+) {
+    for event in collected.read() {
+        if event.kind == PowerupKind::DoubleWidth {
+            // another one while wide just keeps it wide for longer
+            playfield.target = WIDE_SCALE;
+            playfield.timer.reset();
+        }
+    }
+}
+
+fn animate_playfield_scale(mut playfield: ResMut<PlayfieldScaleAnimation>, time: Res<Time>) {
+    if playfield.target > 1. && playfield.timer.tick(time.delta()).finished() {
+        playfield.target = 1.;
+    }
+    if playfield.scale == playfield.target {
+        return;
+    }
+
+    let step = (SCALE_SPEED * time.delta_seconds()).min(1.);
+    playfield.scale = playfield.scale.lerp(playfield.target, step);
+    if (playfield.scale - playfield.target).abs() < SCALE_EPSILON {
+        playfield.scale = playfield.target;
+    }
+}
+
+// Keeps the paddles the usual distance in from the edge of the field
+fn place_paddles(
+    playfield: Res<PlayfieldScaleAnimation>,
+    mut paddles: Query<&mut Position, With<Paddle>>,
+    window: Query<&Window>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    let x = window.resolution.width() / 2. * playfield.scale - PADDLE_PADDING;
+
+    for mut position in &mut paddles {
+        position.0.x = position.0.x.signum() * x;
+    }
+}
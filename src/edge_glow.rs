@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+
+use crate::{Ball, Position};
+
+// distance from an edge at which the glow starts
+const GLOW_THRESHOLD: f32 = 150.;
+const EDGE_THICKNESS: f32 = 8.;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Edge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+#[derive(Component)]
+struct ScreenEdge(Edge);
+
+pub struct ScreenEdgeGlowPlugin;
+
+impl Plugin for ScreenEdgeGlowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_screen_edges)
+            .add_systems(Update, edge_glow);
+    }
+}
+
+fn spawn_screen_edges(mut commands: Commands) {
+    info!("Spawning screen edges");
+
+    for edge in [Edge::Top, Edge::Bottom, Edge::Left, Edge::Right] {
+        let style = match edge {
+            Edge::Top => Style {
+                top: Val::Px(0.),
+                width: Val::Percent(100.),
+                height: Val::Px(EDGE_THICKNESS),
+                ..default()
+            },
+            Edge::Bottom => Style {
+                bottom: Val::Px(0.),
+                width: Val::Percent(100.),
+                height: Val::Px(EDGE_THICKNESS),
+                ..default()
+            },
+            Edge::Left => Style {
+                left: Val::Px(0.),
+                width: Val::Px(EDGE_THICKNESS),
+                height: Val::Percent(100.),
+                ..default()
+            },
+            Edge::Right => Style {
+                right: Val::Px(0.),
+                width: Val::Px(EDGE_THICKNESS),
+                height: Val::Percent(100.),
+                ..default()
+            },
+        };
+
+        commands.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    ..style
+                },
+                background_color: Color::NONE.into(),
+                ..default()
+            },
+            ScreenEdge(edge),
+        ));
+    }
+}
+
+fn edge_glow(
+    balls: Query<&Position, With<Ball>>,
+    mut edges: Query<(&ScreenEdge, &mut BackgroundColor)>,
+    window: Query<&Window>,
+) {
+    if let Ok(window) = window.get_single() {
+        let half_width = window.resolution.width() / 2.;
+        let half_height = window.resolution.height() / 2.;
+
+        for (edge, mut background) in &mut edges {
+            // the closest ball decides how bright an edge glows
+            let alpha = balls
+                .iter()
+                .map(|position| {
+                    let distance_to_edge = match edge.0 {
+                        Edge::Top => half_height - position.0.y,
+                        Edge::Bottom => position.0.y + half_height,
+                        Edge::Left => position.0.x + half_width,
+                        Edge::Right => half_width - position.0.x,
+                    };
+                    (1.0 - distance_to_edge / GLOW_THRESHOLD).max(0.0)
+                })
+                .fold(0., f32::max);
+
+            let color = match edge.0 {
+                Edge::Left | Edge::Right => Color::srgb(1., 0., 0.),
+                Edge::Top | Edge::Bottom => Color::srgb(0., 0.4, 1.),
+            };
+            background.0 = color.with_alpha(alpha.min(1.));
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::{handle_collisions, Ball, BallFrozen, HitCount, LastHitBy, Player, Scored, Scorer};
+
+const MAX_ENERGY: f32 = 100.;
+const ENERGY_PER_HIT: f32 = 8.;
+const ENERGY_LOST_PER_AI_POINT: f32 = 15.;
+// per second while the ball is in play
+const ENERGY_DECAY: f32 = 0.5;
+const ENERGY_BAR_WIDTH: f32 = 60.;
+
+// Charged by the player's returns, and spent on a
+// halved cooldown for their next ability once full
+#[derive(Resource)]
+pub struct PlayerEnergy(pub f32);
+
+impl Default for PlayerEnergy {
+    fn default() -> Self {
+        PlayerEnergy(50.)
+    }
+}
+
+impl PlayerEnergy {
+    pub fn is_full(&self) -> bool {
+        self.0 >= MAX_ENERGY
+    }
+
+    fn add(&mut self, amount: f32) {
+        self.0 = (self.0 + amount).clamp(0., MAX_ENERGY);
+    }
+}
+
+#[derive(Component)]
+struct EnergyBarFill;
+
+pub struct EnergyBarPlugin;
+
+impl Plugin for EnergyBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerEnergy>()
+            .add_systems(Startup, spawn_energy_bar)
+            .add_systems(OnEnter(InMatch), reset_resource::<PlayerEnergy>)
+            .add_systems(
+                FixedUpdate,
+                charge_energy
+                    .after(handle_collisions)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    (drain_energy, tick_energy).run_if(in_state(GameState::Playing)),
+                    update_energy_bar,
+                ),
+            );
+    }
+}
+
+fn charge_energy(
+    mut energy: ResMut<PlayerEnergy>,
+    balls: Query<(&HitCount, &LastHitBy), (With<Ball>, Changed<HitCount>)>,
+    player: Query<Entity, With<Player>>,
+) {
+    let Ok(player) = player.get_single() else {
+        return;
+    };
+
+    for (hit_count, last_hit_by) in &balls {
+        if hit_count.0 > 0 && last_hit_by.0 == Some(player) {
+            energy.add(ENERGY_PER_HIT);
+        }
+    }
+}
+
+fn drain_energy(mut energy: ResMut<PlayerEnergy>, mut events: EventReader<Scored>) {
+    for event in events.read() {
+        if matches!(event.0, Scorer::Ai) {
+            energy.add(-ENERGY_LOST_PER_AI_POINT);
+        }
+    }
+}
+
+fn tick_energy(mut energy: ResMut<PlayerEnergy>, ball_frozen: Res<BallFrozen>, time: Res<Time>) {
+    if !ball_frozen.0 && energy.0 > 0. {
+        energy.add(-ENERGY_DECAY * time.delta_seconds());
+    }
+}
+
+fn spawn_energy_bar(mut commands: Commands) {
+    // under the player's score and ability cooldown
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(82.0),
+                left: Val::Px(10.0),
+                width: Val::Px(ENERGY_BAR_WIDTH),
+                height: Val::Px(4.0),
+                ..default()
+            },
+            background_color: Color::srgba(1., 1., 1., 0.2).into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((NodeBundle::default(), EnergyBarFill));
+        });
+}
+
+// grey when empty, through blue, to yellow when full
+fn energy_color(fraction: f32) -> Color {
+    let empty = Srgba::rgb(0.5, 0.5, 0.5);
+    let half = Srgba::rgb(0.2, 0.4, 1.);
+    let full = Srgba::rgb(1., 0.85, 0.1);
+
+    let color = if fraction < 0.5 {
+        empty.mix(&half, fraction * 2.)
+    } else {
+        half.mix(&full, (fraction - 0.5) * 2.)
+    };
+    color.into()
+}
+
+fn update_energy_bar(
+    energy: Res<PlayerEnergy>,
+    mut fill: Query<(&mut Style, &mut BackgroundColor), With<EnergyBarFill>>,
+) {
+    if !energy.is_changed() {
+        return;
+    }
+
+    if let Ok((mut style, mut background)) = fill.get_single_mut() {
+        let fraction = energy.0 / MAX_ENERGY;
+        style.width = Val::Percent(fraction * 100.);
+        style.height = Val::Percent(100.);
+        background.0 = energy_color(fraction);
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::VecDeque;
+
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+use crate::localization::{t, Locale};
+use crate::{PaddleHit, Scored, Scorer};
+
+const MAX_LOG_ENTRIES: usize = 50;
+const SUMMARY_ENTRIES: usize = 10;
+// rallies at least this long are worth calling out when they end
+const LONG_RALLY_HITS: u32 = 10;
+
+#[derive(Debug, Copy, Clone)]
+enum LogEntry {
+    BallScored(Scorer),
+    PaddleHit,
+    RallyBroke { hits: u32 },
+}
+
+impl LogEntry {
+    fn describe(&self, locale: &Locale) -> String {
+        match self {
+            LogEntry::BallScored(Scorer::Player) => t("player_scored", locale).to_string(),
+            LogEntry::BallScored(Scorer::Ai) => t("ai_scored", locale).to_string(),
+            LogEntry::PaddleHit => t("paddle_hit", locale).to_string(),
+            LogEntry::RallyBroke { hits } => {
+                t("rally_broken", locale).replace("{hits}", &hits.to_string())
+            }
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct EventLog {
+    entries: VecDeque<LogEntry>,
+    rally_hits: u32,
+}
+
+impl EventLog {
+    fn push(&mut self, entry: LogEntry) {
+        self.entries.push_back(entry);
+        if self.entries.len() > MAX_LOG_ENTRIES {
+            self.entries.pop_front();
+        }
+    }
+}
+
+#[derive(Component)]
+struct MatchSummary;
+
+pub struct EventLogPlugin;
+
+impl Plugin for EventLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventLog>()
+            .add_systems(Startup, spawn_match_summary)
+            .add_systems(
+                Update,
+                (
+                    log_events,
+                    toggle_match_summary.run_if(input_just_pressed(KeyCode::Tab)),
+                    update_match_summary.after(log_events),
+                ),
+            );
+    }
+}
+
+fn log_events(
+    mut log: ResMut<EventLog>,
+    mut paddle_hits: EventReader<PaddleHit>,
+    mut scored_events: EventReader<Scored>,
+) {
+    for _ in paddle_hits.read() {
+        log.rally_hits += 1;
+        log.push(LogEntry::PaddleHit);
+    }
+
+    for event in scored_events.read() {
+        let hits = log.rally_hits;
+        if hits >= LONG_RALLY_HITS {
+            log.push(LogEntry::RallyBroke { hits });
+        }
+        log.rally_hits = 0;
+        log.push(LogEntry::BallScored(event.0));
+    }
+}
+
+fn spawn_match_summary(mut commands: Commands) {
+    commands.spawn((
+        TextBundle {
+            visibility: Visibility::Hidden,
+            ..TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 18.,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(80.0),
+                left: Val::Px(10.0),
+                ..default()
+            })
+            .with_background_color(Color::srgba(0., 0., 0., 0.7))
+        },
+        MatchSummary,
+    ));
+}
+
+fn toggle_match_summary(mut summary: Query<&mut Visibility, With<MatchSummary>>) {
+    if let Ok(mut visibility) = summary.get_single_mut() {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+fn update_match_summary(
+    log: Res<EventLog>,
+    locale: Res<Locale>,
+    mut summary: Query<&mut Text, With<MatchSummary>>,
+) {
+    if log.is_changed() || locale.is_changed() {
+        if let Ok(mut text) = summary.get_single_mut() {
+            let skip = log.entries.len().saturating_sub(SUMMARY_ENTRIES);
+            let lines: Vec<String> = log
+                .entries
+                .iter()
+                .skip(skip)
+                .map(|entry| entry.describe(&locale))
+                .collect();
+            text.sections[0].value =
+                format!("{}\n{}", t("match_summary", &locale), lines.join("\n"));
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+use crate::localization::{t, Locale};
+use crate::score::{MatchOver, Scorer};
+use crate::Score;
+
+// Where the game as a whole is at. Gameplay systems, core and
+// feature alike, only run while `Playing`.
+#[derive(States, Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GameState {
+    #[default]
+    Menu,
+    Playing,
+    Paused,
+    GameOver,
+}
+
+// Set while a match is on, paused or not. Per-match state is reset on
+// entering it, and what a match spawned is scoped to it, so pausing
+// doesn't count as leaving the match.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub struct InMatch;
+
+impl ComputedStates for InMatch {
+    type SourceStates = GameState;
+
+    fn compute(state: GameState) -> Option<Self> {
+        match state {
+            GameState::Playing | GameState::Paused => Some(InMatch),
+            GameState::Menu | GameState::GameOver => None,
+        }
+    }
+}
+
+// The two parts of `GameOver`: the winner's victory lap, then the
+// results screen offering a rematch.
+#[derive(SubStates, Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[source(GameState = GameState::GameOver)]
+pub enum GameOverPhase {
+    #[default]
+    VictoryLap,
+    Results,
+}
+
+#[derive(Component)]
+struct MenuScreen;
+
+#[derive(Component)]
+struct PausedScreen;
+
+#[derive(Component)]
+struct GameOverScreen;
+
+#[derive(Component)]
+struct GameModeList;
+
+// How the match that just ended went, for the game over screen.
+// The score itself is cleared as the match ends.
+#[derive(Resource)]
+pub struct MatchResult {
+    pub winner: Scorer,
+    pub player: u32,
+    pub ai: u32,
+}
+
+// The variants picked on the menu, listed there by key with whether
+// they're on. Features register theirs with `add_game_mode`.
+#[derive(Resource, Default)]
+pub struct GameModes(pub Vec<GameMode>);
+
+pub struct GameMode {
+    pub key: KeyCode,
+    pub name: &'static str,
+    pub on: bool,
+}
+
+impl GameModes {
+    pub fn uses(&self, key: KeyCode) -> bool {
+        self.0.iter().any(|mode| mode.key == key)
+    }
+}
+
+pub trait AddGameMode {
+    // `toggle` switches the variant, and only runs on the menu when
+    // `key` is pressed. Every variant starts off.
+    fn add_game_mode<M>(
+        &mut self,
+        key: KeyCode,
+        name: &'static str,
+        toggle: impl IntoSystemConfigs<M>,
+    ) -> &mut Self;
+}
+
+impl AddGameMode for App {
+    fn add_game_mode<M>(
+        &mut self,
+        key: KeyCode,
+        name: &'static str,
+        toggle: impl IntoSystemConfigs<M>,
+    ) -> &mut Self {
+        self.init_resource::<GameModes>();
+        let mut modes = self.world_mut().resource_mut::<GameModes>();
+        assert!(!modes.uses(key), "{key:?} already picks a game mode");
+        modes.0.push(GameMode {
+            key,
+            name,
+            on: false,
+        });
+
+        let flip = move |mut modes: ResMut<GameModes>| {
+            if let Some(mode) = modes.0.iter_mut().find(|mode| mode.key == key) {
+                mode.on = !mode.on;
+            }
+        };
+        self.add_systems(
+            Update,
+            (toggle, flip)
+                .run_if(in_state(GameState::Menu))
+                .run_if(input_just_pressed(key)),
+        )
+    }
+}
+
+pub struct GameStatePlugin;
+
+impl Plugin for GameStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<GameState>()
+            .add_computed_state::<InMatch>()
+            .enable_state_scoped_entities::<InMatch>()
+            .add_sub_state::<GameOverPhase>()
+            .enable_state_scoped_entities::<GameOverPhase>()
+            .add_systems(OnEnter(GameState::Menu), spawn_menu_screen)
+            .add_systems(OnExit(GameState::Menu), despawn_screen::<MenuScreen>)
+            .add_systems(OnEnter(GameState::Paused), spawn_paused_screen)
+            .add_systems(OnExit(GameState::Paused), despawn_screen::<PausedScreen>)
+            .add_systems(OnEnter(GameOverPhase::Results), spawn_game_over_screen)
+            .add_systems(
+                OnExit(GameState::GameOver),
+                despawn_screen::<GameOverScreen>,
+            )
+            // not on leaving `Playing`, which pausing does too
+            .add_systems(OnExit(InMatch), cleanup_playing_resources)
+            .add_systems(
+                Update,
+                (
+                    start_match
+                        .run_if(in_state(GameState::Menu))
+                        .run_if(input_just_pressed(KeyCode::Space)),
+                    end_match.run_if(in_state(GameState::Playing)),
+                    start_match
+                        .run_if(in_state(GameOverPhase::Results))
+                        .run_if(input_just_pressed(KeyCode::Space)),
+                    quit_game
+                        .run_if(in_state(GameOverPhase::Results))
+                        .run_if(input_just_pressed(KeyCode::Escape)),
+                    update_game_mode_list
+                        .run_if(in_state(GameState::Menu))
+                        .run_if(resource_exists_and_changed::<GameModes>),
+                ),
+            );
+    }
+}
+
+// For features to put their per-match resources back at the start of a match
+pub fn reset_resource<R: Resource + Default>(mut resource: ResMut<R>) {
+    *resource = R::default();
+}
+
+pub fn despawn_screen<T: Component>(mut commands: Commands, screens: Query<Entity, With<T>>) {
+    for entity in &screens {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// A screen filling the window, with a big title over a smaller hint.
+// Drawn under the splash screen, over everything else.
+fn spawn_screen(
+    commands: &mut Commands,
+    marker: impl Component,
+    title: &str,
+    hint: &str,
+    background: Color,
+) -> Entity {
+    let text = |value: &str, font_size: f32| {
+        TextBundle::from_section(
+            value,
+            TextStyle {
+                font_size,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(20.),
+                    ..default()
+                },
+                background_color: background.into(),
+                z_index: ZIndex::Global(90),
+                ..default()
+            },
+            marker,
+        ))
+        .with_children(|screen| {
+            screen.spawn(text(title, 80.));
+            screen.spawn(text(hint, 28.));
+        })
+        .id()
+}
+
+// English unless `LocalizationPlugin` is in to pick another
+fn screen_locale(locale: Option<Res<Locale>>) -> Locale {
+    locale.as_deref().copied().unwrap_or_default()
+}
+
+fn spawn_menu_screen(
+    mut commands: Commands,
+    locale: Option<Res<Locale>>,
+    modes: Option<Res<GameModes>>,
+) {
+    let locale = screen_locale(locale);
+    let screen = spawn_screen(
+        &mut commands,
+        MenuScreen,
+        "BEVY PONG",
+        t("menu_start", &locale),
+        Color::BLACK,
+    );
+    if let Some(modes) = modes {
+        commands.entity(screen).with_children(|screen| {
+            screen.spawn((
+                TextBundle::from_section(
+                    game_mode_list(&modes, &locale),
+                    TextStyle {
+                        font_size: 22.,
+                        color: Color::srgb(0.7, 0.7, 0.7),
+                        ..default()
+                    },
+                ),
+                GameModeList,
+            ));
+        });
+    }
+}
+
+fn game_mode_list(modes: &GameModes, locale: &Locale) -> String {
+    modes
+        .0
+        .iter()
+        .map(|mode| {
+            let state = if mode.on { "mode_on" } else { "mode_off" };
+            format!(
+                "{:?} - {}: {}",
+                mode.key,
+                t(mode.name, locale),
+                t(state, locale)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn update_game_mode_list(
+    modes: Res<GameModes>,
+    locale: Option<Res<Locale>>,
+    mut lists: Query<&mut Text, With<GameModeList>>,
+) {
+    let locale = screen_locale(locale);
+    for mut text in &mut lists {
+        text.sections[0].value = game_mode_list(&modes, &locale);
+    }
+}
+
+fn spawn_paused_screen(mut commands: Commands, locale: Option<Res<Locale>>) {
+    let locale = screen_locale(locale);
+    spawn_screen(
+        &mut commands,
+        PausedScreen,
+        t("pause", &locale),
+        "",
+        Color::srgba(0., 0., 0., 0.5),
+    );
+}
+
+fn spawn_game_over_screen(
+    mut commands: Commands,
+    locale: Option<Res<Locale>>,
+    result: Option<Res<MatchResult>>,
+) {
+    let locale = screen_locale(locale);
+    let (title, hint) = match result {
+        Some(result) => {
+            let title = match result.winner {
+                Scorer::Player => "player_wins",
+                Scorer::Ai => "ai_wins",
+            };
+            let hint = format!(
+                "{} - {}\n{}",
+                result.player,
+                result.ai,
+                t("rematch_or_quit", &locale)
+            );
+            (title, hint)
+        }
+        None => ("game_over", t("rematch_or_quit", &locale).to_string()),
+    };
+    spawn_screen(
+        &mut commands,
+        GameOverScreen,
+        t(title, &locale),
+        &hint,
+        Color::srgba(0., 0., 0., 0.8),
+    );
+}
+
+fn start_match(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Playing);
+}
+
+fn end_match(
+    mut commands: Commands,
+    mut match_over: EventReader<MatchOver>,
+    score: Res<Score>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if let Some(event) = match_over.read().last() {
+        commands.insert_resource(MatchResult {
+            winner: event.0,
+            player: score.player,
+            ai: score.ai,
+        });
+        next_state.set(GameState::GameOver);
+    }
+}
+
+fn quit_game(mut exit: EventWriter<AppExit>) {
+    exit.send(AppExit::Success);
+}
+
+// Every match starts from nothing. What a match spawned is scoped to
+// `InMatch` and goes with it, features put their own resources back.
+fn cleanup_playing_resources(mut score: ResMut<Score>) {
+    *score = Score::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    #[derive(Resource, Default)]
+    struct Toggles(u32);
+
+    fn toggle(mut toggles: ResMut<Toggles>) {
+        toggles.0 += 1;
+    }
+
+    #[test]
+    fn game_modes_only_switch_on_the_menu() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Toggles>()
+            .add_game_mode(KeyCode::KeyV, "mode_gravity", toggle);
+
+        // never released, so it stays just pressed from here on
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyV);
+        app.update();
+        assert_eq!(app.world().resource::<Toggles>().0, 1);
+        assert!(app.world().resource::<GameModes>().0[0].on);
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        assert_eq!(app.world().resource::<Toggles>().0, 1);
+        assert!(app.world().resource::<GameModes>().0[0].on);
+    }
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use bevy::sprite::{MaterialMesh2dBundle, Mesh2dHandle};
+
+use crate::{Ball, PositionHistory};
+
+// A see-through copy of a ball, trailing `max_len / 2` steps behind it.
+// Unlike the trail this is one entity per ball, moved every frame.
+#[derive(Component)]
+struct Ghost(Entity);
+
+#[derive(Resource)]
+struct GhostMaterial(Handle<ColorMaterial>);
+
+pub struct BallGhostPlugin;
+
+impl Plugin for BallGhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_ghost_material).add_systems(
+            Update,
+            (spawn_ball_ghost, move_ball_ghosts.after(spawn_ball_ghost)),
+        );
+    }
+}
+
+fn load_ghost_material(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
+    commands.insert_resource(GhostMaterial(
+        materials.add(ColorMaterial::from_color(Color::srgba_u8(50, 100, 200, 77))),
+    ));
+}
+
+fn spawn_ball_ghost(
+    mut commands: Commands,
+    balls: Query<(Entity, &Mesh2dHandle), Added<Ball>>,
+    ghost_material: Res<GhostMaterial>,
+) {
+    for (ball, mesh) in &balls {
+        commands.spawn((
+            Ghost(ball),
+            MaterialMesh2dBundle {
+                mesh: mesh.clone(),
+                material: ghost_material.0.clone(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+        ));
+    }
+}
+
+fn move_ball_ghosts(
+    mut commands: Commands,
+    mut ghosts: Query<(
+        Entity,
+        &Ghost,
+        &mut Transform,
+        &mut Visibility,
+        &mut Mesh2dHandle,
+    )>,
+    balls: Query<(&PositionHistory, &Mesh2dHandle), Without<Ghost>>,
+) {
+    for (entity, ghost, mut transform, mut visibility, mut mesh) in &mut ghosts {
+        let Ok((history, ball_mesh)) = balls.get(ghost.0) else {
+            // the ball was despawned, so its ghost goes too
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        // a ball that grows gets a mesh of its own
+        if *mesh != *ball_mesh {
+            *mesh = ball_mesh.clone();
+        }
+
+        match history.positions.get(history.max_len / 2) {
+            Some(position) => {
+                // just behind the ball
+                transform.translation = position.extend(-0.05);
+                *visibility = Visibility::Visible;
+            }
+            // not enough history yet to trail behind
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use rand::{random, Rng};
+
+use crate::settings;
+
+const MIN_GLITCH_INTERVAL_SECS: f32 = 8.;
+const MAX_GLITCH_INTERVAL_SECS: f32 = 15.;
+const GLITCH_FRAMES: u32 = 5;
+const MAX_CAMERA_SHAKE: f32 = 10.;
+const SCAN_LINE_HEIGHT: f32 = 4.;
+
+#[derive(Resource)]
+struct GlitchTimer(Timer);
+
+impl Default for GlitchTimer {
+    fn default() -> Self {
+        GlitchTimer(Timer::new(random_glitch_interval(), TimerMode::Once))
+    }
+}
+
+// Where things were before the glitch, so they can be put back exactly
+#[derive(Resource, Default)]
+struct ActiveGlitch {
+    frames_left: u32,
+    camera_origin: Vec3,
+    clear_color: Color,
+}
+
+#[derive(Resource)]
+struct GlitchEnabled(bool);
+
+#[derive(Component)]
+struct ScanLine;
+
+pub struct GlitchPlugin;
+
+impl Plugin for GlitchPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GlitchEnabled(settings::load().glitch_effect))
+            .init_resource::<GlitchTimer>()
+            .init_resource::<ActiveGlitch>()
+            .add_systems(
+                Update,
+                (
+                    toggle_glitch_effect,
+                    despawn_scan_lines,
+                    glitch.after(toggle_glitch_effect).after(despawn_scan_lines),
+                ),
+            );
+    }
+}
+
+fn random_glitch_interval() -> Duration {
+    let secs = rand::thread_rng().gen_range(MIN_GLITCH_INTERVAL_SECS..MAX_GLITCH_INTERVAL_SECS);
+    Duration::from_secs_f32(secs)
+}
+
+fn toggle_glitch_effect(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut enabled: ResMut<GlitchEnabled>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F7) {
+        enabled.0 = !enabled.0;
+        let saved = enabled.0;
+        settings::update(|settings| settings.glitch_effect = saved);
+        info!("Glitch effect: {}", enabled.0);
+    }
+}
+
+// scan lines only ever last a single frame
+fn despawn_scan_lines(mut commands: Commands, scan_lines: Query<Entity, With<ScanLine>>) {
+    for entity in &scan_lines {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn glitch(
+    mut commands: Commands,
+    enabled: Res<GlitchEnabled>,
+    mut timer: ResMut<GlitchTimer>,
+    mut active: ResMut<ActiveGlitch>,
+    mut camera: Query<&mut Transform, With<Camera2d>>,
+    mut clear_color: ResMut<ClearColor>,
+    window: Query<&Window>,
+    time: Res<Time>,
+) {
+    let Ok(mut camera_transform) = camera.get_single_mut() else {
+        return;
+    };
+
+    if active.frames_left == 0 {
+        if !enabled.0 || !timer.0.tick(time.delta()).just_finished() {
+            return;
+        }
+
+        timer.0.set_duration(random_glitch_interval());
+        timer.0.reset();
+
+        active.frames_left = GLITCH_FRAMES;
+        active.camera_origin = camera_transform.translation;
+        active.clear_color = clear_color.0;
+    }
+
+    active.frames_left -= 1;
+
+    if active.frames_left == 0 {
+        camera_transform.translation = active.camera_origin;
+        clear_color.0 = active.clear_color;
+        return;
+    }
+
+    let shake = Vec2::new(random::<f32>() - 0.5, random::<f32>() - 0.5) * 2. * MAX_CAMERA_SHAKE;
+    camera_transform.translation = active.camera_origin + shake.extend(0.);
+    clear_color.0 = Color::hsl(random::<f32>() * 360., 0.8, 0.2);
+
+    if let Ok(window) = window.get_single() {
+        let window_width = window.resolution.width();
+        let window_height = window.resolution.height();
+        let y = (random::<f32>() - 0.5) * window_height;
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(1., 1., 1., 0.4),
+                    custom_size: Some(Vec2::new(window_width, SCAN_LINE_HEIGHT)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(0., y, 5.),
+                ..default()
+            },
+            ScanLine,
+        ));
+    }
+}
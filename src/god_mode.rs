@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::{detect_collisions, move_paddles, Ball, Player, Position};
+
+// The player's paddle follows the ball perfectly, for getting
+// through matches quickly while testing what happens at the end
+#[derive(Resource, Default)]
+struct GodMode(bool);
+
+#[derive(Component)]
+struct GodModeBanner;
+
+pub struct GodModePlugin;
+
+impl Plugin for GodModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GodMode>()
+            .add_systems(Startup, spawn_god_mode_banner)
+            .add_systems(Update, toggle_god_mode)
+            .add_systems(
+                FixedUpdate,
+                track_ball
+                    .after(move_paddles)
+                    .before(detect_collisions)
+                    .run_if(god_mode_on)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn god_mode_on(god_mode: Res<GodMode>) -> bool {
+    god_mode.0
+}
+
+fn spawn_god_mode_banner(mut commands: Commands) {
+    commands.spawn((
+        TextBundle {
+            visibility: Visibility::Hidden,
+            ..TextBundle::from_section(
+                "GOD MODE",
+                TextStyle {
+                    font_size: 32.,
+                    color: Color::srgb(1., 0.1, 0.1),
+                    ..default()
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                left: Val::Percent(44.),
+                ..default()
+            })
+        },
+        GodModeBanner,
+    ));
+}
+
+fn toggle_god_mode(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut god_mode: ResMut<GodMode>,
+    mut banner: Query<&mut Visibility, With<GodModeBanner>>,
+) {
+    let ctrl = keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if !(ctrl && keyboard_input.just_pressed(KeyCode::KeyG)) {
+        return;
+    }
+
+    god_mode.0 = !god_mode.0;
+    // a warning so it stands out if it's left on by accident
+    warn!("God mode: {}", god_mode.0);
+    if let Ok(mut visibility) = banner.get_single_mut() {
+        *visibility = if god_mode.0 {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+// After `move_paddles`, so nothing the keys do gets in the way
+fn track_ball(
+    balls: Query<&Position, With<Ball>>,
+    mut player: Query<&mut Position, (With<Player>, Without<Ball>)>,
+) {
+    let Ok(mut paddle) = player.get_single_mut() else {
+        return;
+    };
+
+    // with several balls, guard against the nearest one
+    let nearest = balls.iter().min_by(|a, b| {
+        let distance = |ball: &Position| (ball.0.x - paddle.0.x).abs();
+        distance(a).total_cmp(&distance(b))
+    });
+    if let Some(ball) = nearest {
+        paddle.0.y = ball.0.y;
+    }
+}
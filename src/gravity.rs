@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+use crate::game_state::{AddGameMode, GameState};
+use crate::rotating_playfield::PlayfieldRotation;
+use crate::{handle_collisions, Ball, Velocity, MAX_BALL_SPEED};
+
+// px/s²
+const DEFAULT_GRAVITY: f32 = 200.;
+// the fastest a ball can fall, in px per physics step like every velocity
+const TERMINAL_VELOCITY: f32 = MAX_BALL_SPEED;
+
+// Game variant where balls are pulled towards the bottom of the screen
+#[derive(Resource, Default)]
+struct GravityMode(bool);
+
+#[derive(Component)]
+struct Gravity(f32);
+
+pub struct GravityPlugin;
+
+impl Plugin for GravityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GravityMode>()
+            .add_game_mode(KeyCode::KeyV, "mode_gravity", toggle_gravity_mode)
+            .add_systems(
+                FixedUpdate,
+                (
+                    assign_gravity,
+                    apply_gravity.after(assign_gravity).after(handle_collisions),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn toggle_gravity_mode(mut gravity: ResMut<GravityMode>) {
+    gravity.0 = !gravity.0;
+    info!("Gravity mode: {}", gravity.0);
+}
+
+// covers the balls served after every point as well
+fn assign_gravity(
+    mut commands: Commands,
+    gravity_mode: Res<GravityMode>,
+    balls: Query<(Entity, Has<Gravity>), With<Ball>>,
+) {
+    for (entity, has_gravity) in &balls {
+        if gravity_mode.0 && !has_gravity {
+            commands.entity(entity).insert(Gravity(DEFAULT_GRAVITY));
+        } else if !gravity_mode.0 && has_gravity {
+            commands.entity(entity).remove::<Gravity>();
+        }
+    }
+}
+
+fn apply_gravity(
+    mut balls: Query<(&Gravity, &mut Velocity)>,
+    rotation: Res<PlayfieldRotation>,
+    time: Res<Time>,
+) {
+    // turns along with a rotating playfield
+    let down = rotation.down();
+
+    for (gravity, mut velocity) in &mut balls {
+        // velocities are per step, so px/s² becomes px/step per step
+        let dt = time.delta_seconds();
+        velocity.0 += down * gravity.0 * dt * dt;
+
+        let falling = velocity.0.dot(down);
+        if falling > TERMINAL_VELOCITY {
+            velocity.0 -= down * (falling - TERMINAL_VELOCITY);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+
+use crate::game_state::{GameState, InMatch};
+use crate::powerups::{PowerupCollected, PowerupKind};
+use crate::{handle_collisions, Ball, Position, Velocity, MAX_BALL_SPEED};
+
+// pulls with 0.2 px/step² at 100px away
+const WELL_MASS: f32 = 2000.;
+const WELL_RADIUS: f32 = 15.;
+const WELL_SECS: f32 = 8.;
+// the most the wells can bend a ball's velocity in one step,
+// as the pull grows without bound right at the centre
+const MAX_DEFLECTION: f32 = 2.;
+// radians per second
+const PULSE_SPEED: f32 = 4.;
+const PULSE_SCALE: f32 = 0.2;
+
+#[derive(Component)]
+struct GravityWell {
+    mass: f32,
+}
+
+#[derive(Component)]
+struct WellLifetime(Timer);
+
+pub struct GravityWellPlugin;
+
+impl Plugin for GravityWellPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_gravity_wells.run_if(in_state(GameState::Playing)),
+                pulse_gravity_wells,
+            ),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                apply_gravity_wells.after(handle_collisions),
+                despawn_gravity_wells,
+            )
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+fn spawn_gravity_wells(
+    mut commands: Commands,
+    mut collected: EventReader<PowerupCollected>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for event in collected.read() {
+        if event.kind != PowerupKind::GravityWell {
+            continue;
+        }
+
+        commands.spawn((
+            GravityWell { mass: WELL_MASS },
+            WellLifetime(Timer::from_seconds(WELL_SECS, TimerMode::Once)),
+            StateScoped(InMatch),
+            Position(Vec2::ZERO),
+            MaterialMesh2dBundle {
+                mesh: meshes.add(Circle::new(WELL_RADIUS)).into(),
+                material: materials.add(ColorMaterial::from_color(Color::srgb(0.35, 0.3, 0.8))),
+                ..default()
+            },
+        ));
+    }
+}
+
+fn pulse_gravity_wells(mut wells: Query<&mut Transform, With<GravityWell>>, time: Res<Time>) {
+    let scale = 1. + (time.elapsed_seconds() * PULSE_SPEED).sin() * PULSE_SCALE;
+    for mut transform in &mut wells {
+        transform.scale = Vec3::splat(scale);
+    }
+}
+
+fn despawn_gravity_wells(
+    mut commands: Commands,
+    mut wells: Query<(Entity, &mut WellLifetime)>,
+    time: Res<Time>,
+) {
+    for (entity, mut lifetime) in &mut wells {
+        if lifetime.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn apply_gravity_wells(
+    mut balls: Query<(&Position, &mut Velocity), With<Ball>>,
+    wells: Query<(&Position, &GravityWell), Without<Ball>>,
+) {
+    if wells.is_empty() {
+        return;
+    }
+
+    for (ball_position, mut velocity) in &mut balls {
+        let pull: Vec2 = wells
+            .iter()
+            .map(|(well_position, well)| {
+                let offset = well_position.0 - ball_position.0;
+                well.mass / offset.length_squared().max(1.) * offset.normalize_or_zero()
+            })
+            .sum();
+
+        velocity.0 =
+            (velocity.0 + pull.clamp_length_max(MAX_DEFLECTION)).clamp_length_max(MAX_BALL_SPEED);
+    }
+}
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+
+use crate::ball::handle_collisions;
+use crate::game_state::GameState;
+use crate::score::{detect_scoring, Scored};
+use crate::{Position, Shape};
+
+pub const GUTTER_HEIGHT: f32 = 20.;
+// how many ball impacts a gutter takes before it breaks
+const GUTTER_HEALTH: u32 = 5;
+
+#[derive(Component)]
+pub struct Gutter;
+
+#[derive(Component)]
+pub struct Health(pub u32);
+
+#[derive(Component)]
+pub enum GutterKind {
+    Static,
+    // swings `amplitude` either side of `base_y`
+    Oscillating {
+        base_y: f32,
+        amplitude: f32,
+        frequency: f32,
+        phase: f32,
+    },
+}
+
+#[derive(Bundle)]
+pub struct GutterBundle {
+    pub gutter: Gutter,
+    pub position: Position,
+    pub shape: Shape,
+    pub health: Health,
+    pub kind: GutterKind,
+}
+
+impl GutterBundle {
+    pub fn new(x: f32, y: f32, width: f32) -> Self {
+        GutterBundle {
+            gutter: Gutter,
+            position: Position(Vec2::new(x, y)),
+            shape: Shape(Vec2::new(width, GUTTER_HEIGHT)),
+            health: Health(GUTTER_HEALTH),
+            kind: GutterKind::Static,
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct GutterAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<ColorMaterial>,
+}
+
+impl GutterAssets {
+    pub fn bundle(&self) -> MaterialMesh2dBundle<ColorMaterial> {
+        MaterialMesh2dBundle {
+            mesh: self.mesh.clone().into(),
+            material: self.material.clone(),
+            ..default()
+        }
+    }
+
+    pub fn spawn_gutters(&self, commands: &mut Commands, window_width: f32, window_height: f32) {
+        let top_gutter_y = window_height / 2. - GUTTER_HEIGHT / 2.;
+        let bottom_gutter_y = -window_height / 2. + GUTTER_HEIGHT / 2.;
+
+        let top_gutter = GutterBundle::new(0., top_gutter_y, window_width);
+        let bottom_gutter = GutterBundle::new(0., bottom_gutter_y, window_width);
+
+        commands.spawn((top_gutter, self.bundle()));
+        commands.spawn((bottom_gutter, self.bundle()));
+    }
+}
+
+pub struct GutterPlugin;
+
+impl Plugin for GutterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_gutters).add_systems(
+            FixedUpdate,
+            (
+                break_gutters.after(handle_collisions),
+                reset_gutters.after(detect_scoring),
+            )
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+fn spawn_gutters(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    window: Query<&Window>,
+) {
+    println!("Spawning gutters");
+
+    if let Ok(window) = window.get_single() {
+        let window_width = window.resolution.width();
+        let window_height = window.resolution.height();
+
+        let shape = Mesh::from(Rectangle::new(window_width, GUTTER_HEIGHT));
+        let material = ColorMaterial::from_color(Color::srgb_u8(255, 255, 255));
+
+        let gutter_assets = GutterAssets {
+            mesh: meshes.add(shape),
+            material: materials.add(material),
+        };
+
+        gutter_assets.spawn_gutters(&mut commands, window_width, window_height);
+        commands.insert_resource(gutter_assets);
+    }
+}
+
+pub fn break_gutters(mut commands: Commands, gutters: Query<(Entity, &Health), With<Gutter>>) {
+    for (entity, health) in &gutters {
+        if health.0 == 0 {
+            info!("Gutter broke");
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn reset_gutters(
+    mut commands: Commands,
+    gutters: Query<Entity, With<Gutter>>,
+    gutter_assets: Res<GutterAssets>,
+    window: Query<&Window>,
+    mut events: EventReader<Scored>,
+) {
+    // every point starts with a fresh pair of gutters
+    if events.read().last().is_some() {
+        if let Ok(window) = window.get_single() {
+            for entity in &gutters {
+                commands.entity(entity).despawn();
+            }
+
+            let window_width = window.resolution.width();
+            let window_height = window.resolution.height();
+            gutter_assets.spawn_gutters(&mut commands, window_width, window_height);
+        }
+    }
+}
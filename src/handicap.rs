@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::localization::{t, Locale};
+use crate::{Ai, Paddle, PaddleHeightModifiers, Player, Score, Shape};
+
+// how far behind a player has to fall before they get help
+const HANDICAP_DEFICIT: u32 = 2;
+const BIGGER_PADDLE_SCALE: f32 = 1.3;
+
+// Whether the losing side gets a bigger paddle to catch up
+#[derive(Resource)]
+struct CatchUpMechanic(bool);
+
+impl Default for CatchUpMechanic {
+    fn default() -> Self {
+        CatchUpMechanic(true)
+    }
+}
+
+#[derive(Component)]
+struct BiggerPaddle;
+
+// Floating "ASSIST" label that follows a paddle
+#[derive(Component)]
+struct AssistIndicator(Entity);
+
+pub struct HandicapPlugin;
+
+impl Plugin for HandicapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CatchUpMechanic>().add_systems(
+            Update,
+            (
+                toggle_catch_up_mechanic,
+                apply_handicap
+                    .after(toggle_catch_up_mechanic)
+                    .run_if(in_state(GameState::Playing)),
+                update_assist_indicators.after(apply_handicap),
+            ),
+        );
+    }
+}
+
+fn toggle_catch_up_mechanic(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut catch_up: ResMut<CatchUpMechanic>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F4) {
+        catch_up.0 = !catch_up.0;
+        info!("Catch-up mechanic: {}", catch_up.0);
+    }
+}
+
+fn apply_handicap(
+    mut commands: Commands,
+    catch_up: Res<CatchUpMechanic>,
+    score: Res<Score>,
+    locale: Res<Locale>,
+    mut paddles: Query<
+        (
+            Entity,
+            &mut PaddleHeightModifiers,
+            Has<BiggerPaddle>,
+            Has<Player>,
+        ),
+        (With<Paddle>, Or<(With<Player>, With<Ai>)>),
+    >,
+) {
+    if !score.is_changed() && !catch_up.is_changed() {
+        return;
+    }
+
+    for (entity, mut height, has_bonus, is_player) in &mut paddles {
+        let (own, opponent) = if is_player {
+            (score.player, score.ai)
+        } else {
+            (score.ai, score.player)
+        };
+        let needs_bonus = catch_up.0 && opponent >= own + HANDICAP_DEFICIT;
+
+        if needs_bonus && !has_bonus {
+            height.handicap = BIGGER_PADDLE_SCALE;
+            commands.entity(entity).insert(BiggerPaddle);
+            commands.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        t("assist", &locale),
+                        TextStyle {
+                            font_size: 14.,
+                            color: Color::srgb_u8(120, 220, 120),
+                            ..default()
+                        },
+                    ),
+                    ..default()
+                },
+                AssistIndicator(entity),
+            ));
+        } else if !needs_bonus && has_bonus {
+            height.handicap = 1.;
+            commands.entity(entity).remove::<BiggerPaddle>();
+        }
+    }
+}
+
+fn update_assist_indicators(
+    mut commands: Commands,
+    mut indicators: Query<(Entity, &AssistIndicator, &mut Transform)>,
+    paddles: Query<(&Transform, &Shape), (With<BiggerPaddle>, Without<AssistIndicator>)>,
+) {
+    for (entity, indicator, mut transform) in &mut indicators {
+        if let Ok((paddle_transform, shape)) = paddles.get(indicator.0) {
+            transform.translation =
+                paddle_transform.translation + Vec3::new(0., shape.0.y / 2. + 12., 1.);
+        } else {
+            // the bonus is gone, so is its label
+            commands.entity(entity).despawn();
+        }
+    }
+}
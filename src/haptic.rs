@@ -0,0 +1,210 @@
+// Rumble through Linux force feedback devices, talking to the
+// kernel's evdev interface directly through `ioctl`
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::mem::size_of;
+use std::os::fd::AsRawFd;
+
+use bevy::prelude::*;
+
+use crate::{BallCollision, Scored, Velocity, MAX_BALL_SPEED};
+
+// from linux/input-event-codes.h and linux/input.h
+const EV_FF: u16 = 0x15;
+const FF_RUMBLE: u16 = 0x50;
+const FF_MAX: u16 = 0x7f;
+
+const GOAL_RUMBLE_MS: u16 = 300;
+const BOUNCE_RUMBLE_MS: u16 = 60;
+
+#[derive(Event, Debug, Copy, Clone)]
+pub enum HapticEffect {
+    // `intensity` runs from nothing at 0 to full strength at 255
+    Rumble { duration_ms: u16, intensity: u8 },
+}
+
+// An event device that can rumble, along with the id the kernel gave
+// our effect once it's been uploaded
+struct HapticDevice {
+    file: File,
+    effect_id: i16,
+}
+
+#[derive(Resource, Default)]
+struct HapticDevices(Vec<HapticDevice>);
+
+pub struct HapticPlugin;
+
+impl Plugin for HapticPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HapticDevices>()
+            .add_event::<HapticEffect>()
+            .add_systems(Startup, find_haptic_devices)
+            .add_systems(
+                Update,
+                (
+                    rumble_on_goal,
+                    rumble_on_bounce,
+                    send_haptic.after(rumble_on_goal).after(rumble_on_bounce),
+                ),
+            );
+    }
+}
+
+// _IOC from linux/ioctl.h
+const fn ioc(direction: u64, number: u64, size: usize) -> u64 {
+    (direction << 30) | ((size as u64) << 16) | ((b'E' as u64) << 8) | number
+}
+
+const fn eviocgbit(event_type: u16, len: usize) -> u64 {
+    // _IOC_READ
+    ioc(2, 0x20 + event_type as u64, len)
+}
+
+// _IOW, upload (or update) an effect
+const EVIOCSFF: u64 = ioc(1, 0x80, size_of::<libc::ff_effect>());
+
+fn supports_rumble(file: &File) -> bool {
+    let mut bits = [0u8; FF_MAX as usize / 8 + 1];
+    // SAFETY: the kernel writes at most `bits.len()` bytes into `bits`
+    let result = unsafe {
+        libc::ioctl(
+            file.as_raw_fd(),
+            eviocgbit(EV_FF, bits.len()) as _,
+            bits.as_mut_ptr(),
+        )
+    };
+    result >= 0 && bits[FF_RUMBLE as usize / 8] & (1 << (FF_RUMBLE % 8)) != 0
+}
+
+fn find_haptic_devices(mut devices: ResMut<HapticDevices>) {
+    let Ok(entries) = fs::read_dir("/dev/input") else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_event_device = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("event"));
+        if !is_event_device {
+            continue;
+        }
+
+        // most event devices need the user in the `input` group
+        // to be opened for writing, skip the ones we can't
+        let Ok(file) = OpenOptions::new().read(true).write(true).open(&path) else {
+            continue;
+        };
+        if supports_rumble(&file) {
+            info!("Rumbling through {}", path.display());
+            // -1 asks the kernel for a new effect on first upload
+            devices.0.push(HapticDevice {
+                file,
+                effect_id: -1,
+            });
+        }
+    }
+}
+
+fn rumble_effect(id: i16, duration_ms: u16, intensity: u8) -> libc::ff_effect {
+    let magnitude = u16::from(intensity) * 257;
+    // `u` is a union in C, rumble is its strong then weak magnitude
+    let mut rumble = [0u8; 8];
+    rumble[..2].copy_from_slice(&magnitude.to_ne_bytes());
+    rumble[2..4].copy_from_slice(&magnitude.to_ne_bytes());
+
+    // SAFETY: all zeroes is a valid `ff_effect`, it's plain old data
+    let mut effect: libc::ff_effect = unsafe { std::mem::zeroed() };
+    effect.type_ = FF_RUMBLE;
+    effect.id = id;
+    effect.replay.length = duration_ms;
+    effect.u[0] = u64::from_ne_bytes(rumble);
+    effect
+}
+
+fn play(device: &mut HapticDevice, effect: HapticEffect) -> std::io::Result<()> {
+    let HapticEffect::Rumble {
+        duration_ms,
+        intensity,
+    } = effect;
+
+    let mut ff_effect = rumble_effect(device.effect_id, duration_ms, intensity);
+    // SAFETY: `ff_effect` matches the kernel's `struct ff_effect`,
+    // which it reads and writes the assigned id back into
+    let result = unsafe {
+        libc::ioctl(
+            device.file.as_raw_fd(),
+            EVIOCSFF as _,
+            &mut ff_effect as *mut libc::ff_effect,
+        )
+    };
+    if result < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    device.effect_id = ff_effect.id;
+
+    // playing it is a write of an `EV_FF` event with the effect's id
+    // SAFETY: as for `ff_effect`
+    let mut play: libc::input_event = unsafe { std::mem::zeroed() };
+    play.type_ = EV_FF;
+    play.code = device.effect_id as u16;
+    play.value = 1;
+    // SAFETY: `play` lives for as long as `bytes` and has no padding
+    let bytes = unsafe {
+        std::slice::from_raw_parts(
+            &play as *const libc::input_event as *const u8,
+            size_of::<libc::input_event>(),
+        )
+    };
+    device.file.write_all(bytes)
+}
+
+fn send_haptic(mut devices: ResMut<HapticDevices>, mut effects: EventReader<HapticEffect>) {
+    // a device plays one effect at a time, so only the strongest
+    // of the frame gets played
+    let strongest = effects.read().max_by_key(|effect| match effect {
+        HapticEffect::Rumble { intensity, .. } => *intensity,
+    });
+    let Some(effect) = strongest else {
+        return;
+    };
+
+    devices.0.retain_mut(|device| match play(device, *effect) {
+        Ok(()) => true,
+        Err(err) => {
+            // unplugged, most likely
+            warn!("Dropping haptic device: {err}");
+            false
+        }
+    });
+}
+
+fn rumble_on_goal(mut goals: EventReader<Scored>, mut effects: EventWriter<HapticEffect>) {
+    for _ in goals.read() {
+        effects.send(HapticEffect::Rumble {
+            duration_ms: GOAL_RUMBLE_MS,
+            intensity: u8::MAX,
+        });
+    }
+}
+
+// faster balls hit harder
+fn rumble_on_bounce(
+    mut collisions: EventReader<BallCollision>,
+    balls: Query<&Velocity>,
+    mut effects: EventWriter<HapticEffect>,
+) {
+    for collision in collisions.read() {
+        let Ok(velocity) = balls.get(collision.ball) else {
+            continue;
+        };
+        let strength = (velocity.0.length() / MAX_BALL_SPEED).clamp(0., 1.);
+        effects.send(HapticEffect::Rumble {
+            duration_ms: BOUNCE_RUMBLE_MS,
+            intensity: (strength * 160.) as u8,
+        });
+    }
+}
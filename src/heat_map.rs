@@ -0,0 +1,145 @@
+use bevy::color::Mix;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::render::texture::ImageSampler;
+
+use crate::game_state::GameState;
+use crate::{move_ball, Ball, Position};
+
+const COLS: usize = 32;
+const ROWS: usize = 18;
+const OVERLAY_ALPHA: f32 = 0.6;
+
+// How many physics steps a ball has spent in each cell of the field,
+// top row first. Only kept for the current session.
+#[derive(Resource)]
+struct HeatMap([[f32; COLS]; ROWS]);
+
+impl Default for HeatMap {
+    fn default() -> Self {
+        HeatMap([[0.; COLS]; ROWS])
+    }
+}
+
+#[derive(Component)]
+struct HeatMapOverlay;
+
+pub struct HeatMapPlugin;
+
+impl Plugin for HeatMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HeatMap>()
+            .add_systems(Startup, spawn_heat_map_overlay)
+            .add_systems(
+                FixedUpdate,
+                record_heat_map
+                    .after(move_ball)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, toggle_heat_map);
+    }
+}
+
+fn record_heat_map(
+    mut heat_map: ResMut<HeatMap>,
+    balls: Query<&Position, With<Ball>>,
+    window: Query<&Window>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    let size = window.resolution.size();
+
+    for position in &balls {
+        let col = ((position.0.x + size.x / 2.) / size.x * COLS as f32).floor();
+        let row = ((size.y / 2. - position.0.y) / size.y * ROWS as f32).floor();
+        // scoring balls are briefly outside the field
+        if (0. ..COLS as f32).contains(&col) && (0. ..ROWS as f32).contains(&row) {
+            heat_map.0[row as usize][col as usize] += 1.;
+        }
+    }
+}
+
+// The overlay is a single sprite showing a COLS x ROWS image,
+// one pixel per cell, stretched over the window
+fn spawn_heat_map_overlay(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: COLS as u32,
+            height: ROWS as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    // keep the cells crisp instead of blurring them together
+    image.sampler = ImageSampler::nearest();
+
+    commands.spawn((
+        SpriteBundle {
+            texture: images.add(image),
+            visibility: Visibility::Hidden,
+            // over the field, under the UI
+            transform: Transform::from_xyz(0., 0., 4.),
+            ..default()
+        },
+        HeatMapOverlay,
+    ));
+}
+
+// There is no game-over or stats screen yet, so H shows the heat map
+// gathered so far and Shift+H clears it
+fn toggle_heat_map(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut heat_map: ResMut<HeatMap>,
+    mut images: ResMut<Assets<Image>>,
+    mut overlay: Query<(&mut Visibility, &mut Sprite, &Handle<Image>), With<HeatMapOverlay>>,
+    window: Query<&Window>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyH) {
+        return;
+    }
+
+    let Ok((mut visibility, mut sprite, handle)) = overlay.get_single_mut() else {
+        return;
+    };
+
+    if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        *heat_map = HeatMap::default();
+        *visibility = Visibility::Hidden;
+        info!("Heat map cleared");
+        return;
+    }
+
+    if *visibility != Visibility::Hidden {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    let max_visits = heat_map.0.iter().flatten().copied().fold(0., f32::max);
+    let cold = Color::srgba(0., 0., 0.4, OVERLAY_ALPHA).to_linear();
+    let hot = Color::srgba(1., 0., 0., OVERLAY_ALPHA).to_linear();
+    if let Some(image) = images.get_mut(handle) {
+        for (pixel, visits) in image
+            .data
+            .chunks_exact_mut(4)
+            .zip(heat_map.0.iter().flatten())
+        {
+            let heat = if max_visits > 0. {
+                visits / max_visits
+            } else {
+                0.
+            };
+            let color = Color::from(cold.mix(&hot, heat)).to_srgba().to_u8_array();
+            pixel.copy_from_slice(&color);
+        }
+    }
+
+    if let Ok(window) = window.get_single() {
+        sprite.custom_size = Some(window.resolution.size());
+    }
+    *visibility = Visibility::Visible;
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use crate::collision::Collision;
+use crate::{BallCollision, Position, Shape};
+
+const RING_LIFETIME_SECS: f32 = 0.25;
+const RING_MAX_RADIUS: f32 = 20.;
+
+// A ring spreading out from where a ball touched something
+struct ImpactRing {
+    center: Vec2,
+    age: f32,
+}
+
+#[derive(Resource, Default)]
+struct ImpactRings(Vec<ImpactRing>);
+
+pub struct ImpactRingPlugin;
+
+impl Plugin for ImpactRingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ImpactRings>()
+            .add_systems(Update, (spawn_impact_rings, draw_impact_rings).chain());
+    }
+}
+
+fn spawn_impact_rings(
+    mut collisions: EventReader<BallCollision>,
+    balls: Query<(&Position, &Shape)>,
+    mut rings: ResMut<ImpactRings>,
+) {
+    for collision in collisions.read() {
+        let Ok((position, shape)) = balls.get(collision.ball) else {
+            continue;
+        };
+        // on the edge of the ball, on the side that was hit
+        let towards_hit = match collision.side {
+            Collision::Left => Vec2::NEG_X,
+            Collision::Right => Vec2::X,
+            Collision::Top => Vec2::Y,
+            Collision::Bottom => Vec2::NEG_Y,
+        };
+        rings.0.push(ImpactRing {
+            center: position.0 + towards_hit * shape.0.x,
+            age: 0.,
+        });
+    }
+}
+
+// Drawn as gizmos, like the speed lines, so nothing needs to be spawned
+fn draw_impact_rings(time: Res<Time>, mut rings: ResMut<ImpactRings>, mut gizmos: Gizmos) {
+    let delta = time.delta_seconds();
+    rings.0.retain_mut(|ring| {
+        ring.age += delta;
+        ring.age < RING_LIFETIME_SECS
+    });
+
+    for ring in &rings.0 {
+        let progress = ring.age / RING_LIFETIME_SECS;
+        gizmos.circle_2d(
+            ring.center,
+            RING_MAX_RADIUS * progress,
+            Color::srgba(1., 1., 1., 1. - progress),
+        );
+    }
+}
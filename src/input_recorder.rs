@@ -0,0 +1,185 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+
+use bevy::core::FrameCount;
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::reflect::{DynamicEnum, DynamicVariant, Enum, TypeInfo, Typed};
+use bytemuck::{Pod, Zeroable};
+
+// One key press or release as written to a recording, 10 bytes each.
+// The key is its index among `KeyCode`'s variants.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct InputRecord {
+    frame: u64,
+    key: u8,
+    pressed: u8,
+}
+
+const RECORD_SIZE: usize = std::mem::size_of::<InputRecord>();
+
+// `None` for keys the OS couldn't name, which have no fixed index
+fn key_index(key: KeyCode) -> Option<u8> {
+    match key {
+        KeyCode::Unidentified(_) => None,
+        key => u8::try_from(key.variant_index()).ok(),
+    }
+}
+
+fn key_from_index(index: u8) -> Option<KeyCode> {
+    let TypeInfo::Enum(info) = KeyCode::type_info() else {
+        return None;
+    };
+    let variant = info.variant_at(index as usize)?;
+    KeyCode::from_reflect(&DynamicEnum::new(variant.name(), DynamicVariant::Unit))
+}
+
+// Writes every key press and release to a file, started with `--record <file>`
+#[derive(Resource)]
+struct InputRecorder {
+    file: BufWriter<File>,
+}
+
+// (frame number, key, pressed) triples, replayed in order.
+// Loaded with `--playback <file>`, and used directly by tests.
+#[derive(Resource)]
+pub struct InputPlayback {
+    pub inputs: Vec<(u64, KeyCode, bool)>,
+}
+
+pub struct InputRecorderPlugin;
+
+impl Plugin for InputRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        // both run after bevy has processed the real keyboard
+        // events for the frame, so played back presses stick
+        app.add_systems(
+            PreUpdate,
+            (
+                record_inputs.run_if(resource_exists::<InputRecorder>),
+                play_back_inputs.run_if(resource_exists::<InputPlayback>),
+            )
+                .after(InputSystem),
+        );
+
+        let args: Vec<String> = std::env::args().collect();
+        let flag_value = |flag: &str| {
+            args.iter()
+                .position(|arg| arg == flag)
+                .and_then(|index| args.get(index + 1))
+        };
+
+        if let Some(path) = flag_value("--record") {
+            match File::create(path) {
+                Ok(file) => {
+                    info!("Recording inputs to {path}");
+                    app.insert_resource(InputRecorder {
+                        file: BufWriter::new(file),
+                    });
+                }
+                Err(err) => error!("Failed to create {path}: {err}"),
+            }
+        }
+
+        if let Some(path) = flag_value("--playback") {
+            match fs::read(path) {
+                Ok(contents) => {
+                    info!("Playing back inputs from {path}");
+                    app.insert_resource(InputPlayback {
+                        inputs: decode_inputs(&contents),
+                    });
+                }
+                Err(err) => error!("Failed to read {path}: {err}"),
+            }
+        }
+    }
+}
+
+// a trailing partial record, e.g. from a crash mid-write, is dropped
+fn decode_inputs(contents: &[u8]) -> Vec<(u64, KeyCode, bool)> {
+    contents
+        .chunks_exact(RECORD_SIZE)
+        .map(bytemuck::pod_read_unaligned::<InputRecord>)
+        .filter_map(|record| {
+            let key = key_from_index(record.key)?;
+            Some((record.frame, key, record.pressed != 0))
+        })
+        .collect()
+}
+
+fn record_inputs(
+    mut recorder: ResMut<InputRecorder>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    frame: Res<FrameCount>,
+) {
+    let presses = keyboard_input.get_just_pressed().map(|key| (key, true));
+    let releases = keyboard_input.get_just_released().map(|key| (key, false));
+
+    let mut written = false;
+    for (&key, pressed) in presses.chain(releases) {
+        let Some(key) = key_index(key) else {
+            continue;
+        };
+        let record = InputRecord {
+            frame: frame.0 as u64,
+            key,
+            pressed: pressed as u8,
+        };
+        if let Err(err) = recorder.file.write_all(bytemuck::bytes_of(&record)) {
+            error!("Failed to record input: {err}");
+        }
+        written = true;
+    }
+
+    // flushed as we go, so the file is usable however the game exits
+    if written {
+        if let Err(err) = recorder.file.flush() {
+            error!("Failed to record input: {err}");
+        }
+    }
+}
+
+fn play_back_inputs(
+    playback: Res<InputPlayback>,
+    frame: Res<FrameCount>,
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+) {
+    for &(_, key, pressed) in playback
+        .inputs
+        .iter()
+        .filter(|(input_frame, _, _)| *input_frame == frame.0 as u64)
+    {
+        if pressed {
+            keyboard_input.press(key);
+        } else {
+            keyboard_input.release(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn any_key_survives_a_round_trip() {
+        let mut contents = Vec::new();
+        // F35 has the highest index
+        for (frame, key) in [(42, KeyCode::KeyQ), (43, KeyCode::F35)] {
+            let record = InputRecord {
+                frame,
+                key: key_index(key).unwrap(),
+                pressed: 1,
+            };
+            contents.extend_from_slice(bytemuck::bytes_of(&record));
+        }
+        // cut off mid-write
+        contents.extend_from_slice(&[44, 0, 0]);
+
+        assert_eq!(
+            decode_inputs(&contents),
+            vec![(42, KeyCode::KeyQ, true), (43, KeyCode::F35, true)]
+        );
+    }
+}
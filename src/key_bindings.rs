@@ -0,0 +1,365 @@
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game_state::GameModes;
+use crate::localization::{t, Locale};
+use crate::settings;
+
+// The keys for everything a player does during a match. Missing
+// fields fall back to their defaults as actions get added.
+#[derive(Resource, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub player_up: KeyCode,
+    pub player_down: KeyCode,
+    pub ai_up: KeyCode,
+    pub ai_down: KeyCode,
+    pub pause: KeyCode,
+    pub serve: KeyCode,
+    pub absorb: KeyCode,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            player_up: KeyCode::KeyY,
+            player_down: KeyCode::KeyN,
+            ai_up: KeyCode::KeyW,
+            ai_down: KeyCode::KeyX,
+            pause: KeyCode::Escape,
+            serve: KeyCode::Space,
+            absorb: KeyCode::KeyA,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Action {
+    PlayerUp,
+    PlayerDown,
+    AiUp,
+    AiDown,
+    Pause,
+    Serve,
+    Absorb,
+}
+
+impl Action {
+    const ALL: [Action; 7] = [
+        Action::PlayerUp,
+        Action::PlayerDown,
+        Action::AiUp,
+        Action::AiDown,
+        Action::Pause,
+        Action::Serve,
+        Action::Absorb,
+    ];
+
+    fn label_key(self) -> &'static str {
+        match self {
+            Action::PlayerUp => "bind_player_up",
+            Action::PlayerDown => "bind_player_down",
+            Action::AiUp => "bind_ai_up",
+            Action::AiDown => "bind_ai_down",
+            Action::Pause => "bind_pause",
+            Action::Serve => "bind_serve",
+            Action::Absorb => "bind_absorb",
+        }
+    }
+}
+
+impl KeyBindings {
+    fn key_mut(&mut self, action: Action) -> &mut KeyCode {
+        match action {
+            Action::PlayerUp => &mut self.player_up,
+            Action::PlayerDown => &mut self.player_down,
+            Action::AiUp => &mut self.ai_up,
+            Action::AiDown => &mut self.ai_down,
+            Action::Pause => &mut self.pause,
+            Action::Serve => &mut self.serve,
+            Action::Absorb => &mut self.absorb,
+        }
+    }
+
+    fn key(&self, action: Action) -> KeyCode {
+        match action {
+            Action::PlayerUp => self.player_up,
+            Action::PlayerDown => self.player_down,
+            Action::AiUp => self.ai_up,
+            Action::AiDown => self.ai_down,
+            Action::Pause => self.pause,
+            Action::Serve => self.serve,
+            Action::Absorb => self.absorb,
+        }
+    }
+}
+
+// Where the remapping screen is at. While waiting, the
+// next key pressed is bound to the action.
+#[derive(Resource, Default, Debug, PartialEq, Eq)]
+enum KeyBindingScreen {
+    #[default]
+    Closed,
+    Open,
+    WaitingForKey(Action),
+}
+
+// key of the message under the rows, if there is one
+#[derive(Resource, Default)]
+struct BindingError(Option<&'static str>);
+
+#[derive(Component)]
+struct KeyBindingRoot;
+
+#[derive(Component)]
+struct ActionLabel(Action);
+
+#[derive(Component)]
+struct RebindButton(Action);
+
+#[derive(Component)]
+struct RebindButtonText(Action);
+
+#[derive(Component)]
+struct KeyBindingTitle;
+
+#[derive(Component)]
+struct KeyBindingErrorText;
+
+pub struct KeyBindingsPlugin;
+
+impl Plugin for KeyBindingsPlugin {
+    fn build(&self, app: &mut App) {
+        // loaded up front so gameplay reads the right keys from the start
+        app.insert_resource(settings::load().key_bindings)
+            .init_resource::<KeyBindingScreen>()
+            .init_resource::<BindingError>()
+            .add_systems(Startup, spawn_key_binding_screen)
+            .add_systems(
+                Update,
+                (
+                    toggle_key_binding_screen,
+                    start_rebinding.after(toggle_key_binding_screen),
+                    capture_key.after(start_rebinding),
+                    update_key_binding_screen.after(capture_key),
+                ),
+            );
+    }
+}
+
+fn text_style(font_size: f32) -> TextStyle {
+    TextStyle {
+        font_size,
+        color: Color::WHITE,
+        ..default()
+    }
+}
+
+fn spawn_key_binding_screen(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                visibility: Visibility::Hidden,
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(20.),
+                    left: Val::Percent(30.),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.),
+                    padding: UiRect::all(Val::Px(10.)),
+                    ..default()
+                },
+                background_color: Color::srgba(0., 0., 0., 0.8).into(),
+                ..default()
+            },
+            KeyBindingRoot,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section("", text_style(24.)),
+                KeyBindingTitle,
+            ));
+
+            for action in Action::ALL {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            column_gap: Val::Px(12.),
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            TextBundle::from_section("", text_style(18.)).with_style(Style {
+                                width: Val::Px(140.),
+                                ..default()
+                            }),
+                            ActionLabel(action),
+                        ));
+                        row.spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    padding: UiRect::axes(Val::Px(8.), Val::Px(4.)),
+                                    ..default()
+                                },
+                                background_color: Color::srgb(0.25, 0.25, 0.25).into(),
+                                ..default()
+                            },
+                            RebindButton(action),
+                        ))
+                        .with_children(|button| {
+                            button.spawn((
+                                TextBundle::from_section("", text_style(18.)),
+                                RebindButtonText(action),
+                            ));
+                        });
+                    });
+            }
+
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        color: Color::srgb(0.95, 0.3, 0.3),
+                        ..text_style(16.)
+                    },
+                ),
+                KeyBindingErrorText,
+            ));
+        });
+}
+
+// There is no settings menu yet, so K opens the screen
+fn toggle_key_binding_screen(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut screen: ResMut<KeyBindingScreen>,
+    mut error: ResMut<BindingError>,
+    mut root: Query<&mut Visibility, With<KeyBindingRoot>>,
+) {
+    // K is bindable too, so it can't close the screen mid rebind
+    if !keyboard_input.just_pressed(KeyCode::KeyK)
+        || matches!(*screen, KeyBindingScreen::WaitingForKey(_))
+    {
+        return;
+    }
+
+    *screen = match *screen {
+        KeyBindingScreen::Closed => KeyBindingScreen::Open,
+        _ => KeyBindingScreen::Closed,
+    };
+    error.0 = None;
+    if let Ok(mut visibility) = root.get_single_mut() {
+        *visibility = if *screen == KeyBindingScreen::Closed {
+            Visibility::Hidden
+        } else {
+            Visibility::Visible
+        };
+    }
+}
+
+fn start_rebinding(
+    mut screen: ResMut<KeyBindingScreen>,
+    mut error: ResMut<BindingError>,
+    buttons: Query<(&Interaction, &RebindButton), Changed<Interaction>>,
+) {
+    if *screen == KeyBindingScreen::Closed {
+        return;
+    }
+
+    for (interaction, button) in &buttons {
+        if *interaction == Interaction::Pressed {
+            *screen = KeyBindingScreen::WaitingForKey(button.0);
+            error.0 = None;
+        }
+    }
+}
+
+fn capture_key(
+    mut screen: ResMut<KeyBindingScreen>,
+    mut error: ResMut<BindingError>,
+    mut bindings: ResMut<KeyBindings>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    modes: Option<Res<GameModes>>,
+) {
+    let KeyBindingScreen::WaitingForKey(action) = *screen else {
+        keyboard_events.clear();
+        return;
+    };
+
+    let Some(key_code) = keyboard_events
+        .read()
+        .filter(|event| event.state == ButtonState::Pressed)
+        .map(|event| event.key_code)
+        .last()
+    else {
+        return;
+    };
+
+    // the game mode keys only do anything on the menu, but a key that
+    // does two things is still confusing
+    let taken = Action::ALL
+        .iter()
+        .any(|&other| other != action && bindings.key(other) == key_code)
+        || modes.is_some_and(|modes| modes.uses(key_code));
+    if taken {
+        error.0 = Some("binding_taken");
+    } else {
+        *bindings.key_mut(action) = key_code;
+        let saved = bindings.clone();
+        settings::update(|settings| settings.key_bindings = saved);
+        info!("Bound {action:?} to {key_code:?}");
+    }
+    *screen = KeyBindingScreen::Open;
+}
+
+fn update_key_binding_screen(
+    screen: Res<KeyBindingScreen>,
+    error: Res<BindingError>,
+    bindings: Res<KeyBindings>,
+    locale: Res<Locale>,
+    mut title: Query<&mut Text, With<KeyBindingTitle>>,
+    mut labels: Query<(&mut Text, &ActionLabel), Without<KeyBindingTitle>>,
+    mut button_texts: Query<
+        (&mut Text, &RebindButtonText),
+        (Without<KeyBindingTitle>, Without<ActionLabel>),
+    >,
+    mut error_text: Query<
+        &mut Text,
+        (
+            With<KeyBindingErrorText>,
+            Without<KeyBindingTitle>,
+            Without<ActionLabel>,
+            Without<RebindButtonText>,
+        ),
+    >,
+) {
+    if !screen.is_changed() && !error.is_changed() && !bindings.is_changed() && !locale.is_changed()
+    {
+        return;
+    }
+
+    if let Ok(mut text) = title.get_single_mut() {
+        text.sections[0].value = t("key_bindings", &locale).to_string();
+    }
+    for (mut text, label) in &mut labels {
+        text.sections[0].value = t(label.0.label_key(), &locale).to_string();
+    }
+    for (mut text, button) in &mut button_texts {
+        text.sections[0].value = if *screen == KeyBindingScreen::WaitingForKey(button.0) {
+            t("press_a_key", &locale).to_string()
+        } else {
+            format!(
+                "{:?} - {}",
+                bindings.key(button.0),
+                t("press_to_rebind", &locale)
+            )
+        };
+    }
+    if let Ok(mut text) = error_text.get_single_mut() {
+        text.sections[0].value = error.0.map(|key| t(key, &locale)).unwrap_or("").to_string();
+    }
+}
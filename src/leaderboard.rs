@@ -0,0 +1,248 @@
+use std::fs;
+
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use bevy::scene::ron;
+use serde::{Deserialize, Serialize};
+
+use crate::localization::{t, Locale};
+use crate::{PaddleHit, Scored};
+
+const HISTORY_PATH: &str = "stats_history.ron";
+const GOLD: Color = Color::srgb(1., 0.84, 0.);
+
+// One finished session's rallies, as kept in the history file
+#[derive(Serialize, Deserialize)]
+struct SessionRecord {
+    rallies: Vec<u32>,
+}
+
+// Rallies played so far this session, and every earlier session
+#[derive(Resource, Default)]
+struct SessionStats {
+    rallies: Vec<u32>,
+    current_rally: u32,
+    history: Vec<SessionRecord>,
+}
+
+// This session against the ones before it
+struct StatsComparison {
+    rally_avg: f32,
+    all_time_avg: f32,
+    // positive when this session is doing better
+    rally_avg_delta: f32,
+    best_rally: u32,
+    personal_best: u32,
+    beat_personal_best: bool,
+}
+
+fn average(rallies: impl Iterator<Item = u32>) -> f32 {
+    let (total, count) = rallies.fold((0, 0), |(total, count), hits| (total + hits, count + 1));
+    if count == 0 {
+        0.
+    } else {
+        total as f32 / count as f32
+    }
+}
+
+fn compare_stats(session: &[u32], history: &[SessionRecord]) -> StatsComparison {
+    let past = || {
+        history
+            .iter()
+            .flat_map(|record| record.rallies.iter().copied())
+    };
+
+    let rally_avg = average(session.iter().copied());
+    // all time includes this session
+    let all_time_avg = average(past().chain(session.iter().copied()));
+    let best_rally = session.iter().copied().max().unwrap_or(0);
+    let personal_best = past().max().unwrap_or(0);
+
+    StatsComparison {
+        rally_avg,
+        all_time_avg,
+        rally_avg_delta: rally_avg - all_time_avg,
+        best_rally,
+        personal_best,
+        beat_personal_best: best_rally > personal_best,
+    }
+}
+
+fn load_history() -> Vec<SessionRecord> {
+    match fs::read_to_string(HISTORY_PATH) {
+        Ok(contents) => ron::from_str(&contents).unwrap_or_else(|err| {
+            warn!("Failed to parse {HISTORY_PATH}, starting afresh: {err}");
+            Vec::new()
+        }),
+        // no sessions played yet
+        Err(_) => Vec::new(),
+    }
+}
+
+// Written after every rally, so the session is kept however the game ends
+fn save_history(history: &[SessionRecord], session: &SessionRecord) {
+    let all: Vec<&SessionRecord> = history.iter().chain(std::iter::once(session)).collect();
+    match ron::ser::to_string_pretty(&all, ron::ser::PrettyConfig::default()) {
+        Ok(serialized) => {
+            if let Err(err) = fs::write(HISTORY_PATH, serialized) {
+                error!("Failed to write {HISTORY_PATH}: {err}");
+            }
+        }
+        Err(err) => error!("Failed to serialize stats history: {err}"),
+    }
+}
+
+#[derive(Component)]
+struct Leaderboard;
+
+// One value cell of the table
+#[derive(Component, Copy, Clone)]
+enum StatCell {
+    RallyAvg,
+    AllTimeAvg,
+    BestRally,
+    PersonalBest,
+}
+
+// The localized text cells, by localization key
+#[derive(Component)]
+struct StatLabel(&'static str);
+
+pub struct LeaderboardPlugin;
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SessionStats {
+            history: load_history(),
+            ..default()
+        })
+        .add_systems(Startup, spawn_leaderboard)
+        .add_systems(
+            Update,
+            (
+                track_rallies,
+                // There is no game over screen yet, so L shows the table
+                toggle_leaderboard.run_if(input_just_pressed(KeyCode::KeyL)),
+                update_leaderboard.after(track_rallies),
+            ),
+        );
+    }
+}
+
+fn track_rallies(
+    mut stats: ResMut<SessionStats>,
+    mut paddle_hits: EventReader<PaddleHit>,
+    mut scored_events: EventReader<Scored>,
+) {
+    let hits = paddle_hits.read().count() as u32;
+    if hits > 0 {
+        stats.current_rally += hits;
+    }
+
+    for _ in scored_events.read() {
+        let rally = std::mem::take(&mut stats.current_rally);
+        stats.rallies.push(rally);
+        let session = SessionRecord {
+            rallies: stats.rallies.clone(),
+        };
+        save_history(&stats.history, &session);
+    }
+}
+
+fn spawn_leaderboard(mut commands: Commands) {
+    let cell = |text: &str| {
+        TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 20.,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+    };
+    let column = || NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.),
+            ..default()
+        },
+        ..default()
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                visibility: Visibility::Hidden,
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(30.),
+                    left: Val::Percent(30.),
+                    column_gap: Val::Px(24.),
+                    padding: UiRect::all(Val::Px(12.)),
+                    ..default()
+                },
+                background_color: Color::srgba(0., 0., 0., 0.8).into(),
+                ..default()
+            },
+            Leaderboard,
+        ))
+        .with_children(|table| {
+            table.spawn(column()).with_children(|labels| {
+                labels.spawn((cell(""), StatLabel("leaderboard")));
+                labels.spawn((cell(""), StatLabel("rally_avg")));
+                labels.spawn((cell(""), StatLabel("best_rally")));
+            });
+            table.spawn(column()).with_children(|session| {
+                session.spawn((cell(""), StatLabel("this_session")));
+                session.spawn((cell(""), StatCell::RallyAvg));
+                session.spawn((cell(""), StatCell::BestRally));
+            });
+            table.spawn(column()).with_children(|all_time| {
+                all_time.spawn((cell(""), StatLabel("all_time")));
+                all_time.spawn((cell(""), StatCell::AllTimeAvg));
+                all_time.spawn((cell(""), StatCell::PersonalBest));
+            });
+        });
+}
+
+fn toggle_leaderboard(mut leaderboard: Query<&mut Visibility, With<Leaderboard>>) {
+    if let Ok(mut visibility) = leaderboard.get_single_mut() {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+fn update_leaderboard(
+    stats: Res<SessionStats>,
+    locale: Res<Locale>,
+    mut labels: Query<(&mut Text, &StatLabel)>,
+    mut cells: Query<(&mut Text, &StatCell), Without<StatLabel>>,
+) {
+    if !stats.is_changed() && !locale.is_changed() {
+        return;
+    }
+
+    for (mut text, label) in &mut labels {
+        text.sections[0].value = t(label.0, &locale).to_string();
+    }
+
+    let comparison = compare_stats(&stats.rallies, &stats.history);
+    for (mut text, cell) in &mut cells {
+        let section = &mut text.sections[0];
+        section.value = match cell {
+            StatCell::RallyAvg => format!(
+                "{:.1} ({:+.1})",
+                comparison.rally_avg, comparison.rally_avg_delta
+            ),
+            StatCell::AllTimeAvg => format!("{:.1}", comparison.all_time_avg),
+            StatCell::BestRally => comparison.best_rally.to_string(),
+            StatCell::PersonalBest => comparison.personal_best.to_string(),
+        };
+        section.style.color = match cell {
+            StatCell::BestRally if comparison.beat_personal_best => GOLD,
+            _ => Color::WHITE,
+        };
+    }
+}
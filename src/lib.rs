@@ -0,0 +1,396 @@
+// Bevy queries and system params get long by nature
+#![allow(clippy::type_complexity, clippy::too_many_arguments)]
+
+use bevy::prelude::*;
+
+mod absorb;
+mod ai;
+mod ball;
+mod ball_size;
+mod boomerang;
+mod brick_breaker;
+mod burst;
+mod camera;
+mod center_paddle;
+mod challenge_mode;
+mod changelog;
+mod checkpoint;
+mod collision;
+mod collision_audio;
+mod color_blind;
+mod confetti;
+mod db;
+#[cfg(debug_assertions)]
+mod debug_overlay;
+mod double_width;
+mod edge_glow;
+mod energy;
+mod event_log;
+mod game_state;
+mod ghost;
+mod glitch;
+#[cfg(debug_assertions)]
+mod god_mode;
+mod gravity;
+mod gravity_well;
+mod gutter;
+mod handicap;
+#[cfg(all(target_os = "linux", feature = "haptic"))]
+mod haptic;
+mod heat_map;
+mod impact;
+mod input_recorder;
+mod key_bindings;
+mod leaderboard;
+mod localization;
+mod match_history;
+mod monitor;
+mod mouse_mode;
+mod moving_gutters;
+mod multiball;
+mod music;
+mod narrator;
+mod paddle;
+mod paddle_customization;
+mod paddle_grow;
+mod paddle_resize;
+mod portal;
+mod post_score_freeze;
+mod powerups;
+mod profile;
+mod projectile;
+mod racket_spin;
+mod rally;
+mod rebound_paddle;
+mod replay;
+mod rotating_playfield;
+mod save_state;
+mod score;
+mod score_flash;
+#[cfg(debug_assertions)]
+mod screenshot;
+mod serve;
+mod settings;
+mod simulation_speed;
+mod slippery_floor;
+mod slow_motion;
+mod slow_zone;
+mod speed_lines;
+mod splash;
+mod streak;
+mod target_zone;
+mod team;
+mod tennis;
+#[cfg(test)]
+mod test_mode;
+mod toast;
+mod tournament;
+mod trail;
+mod tutorial;
+mod ui;
+#[cfg(debug_assertions)]
+mod undo;
+mod victory_lap;
+#[cfg(feature = "video_capture")]
+mod video_capture;
+mod visual_novel;
+mod wall_breaker;
+mod wormhole;
+mod zipline;
+mod zoom_camera;
+
+use absorb::PaddleAbsorbPlugin;
+use ai::{AiMistakesPlugin, AiPlugin};
+use ball::{
+    detect_collisions, handle_collisions, move_ball, random_serve_velocity, reset_ball, BallAssets,
+    BallBundle, BallCollision, BallFrozen, BallPlugin, HitCount, LastHitBy, PaddleHit,
+    PositionHistory, PrevPosition, BALL_RADIUS, MAX_BALL_SPEED, MIN_BALL_SPEED,
+};
+use ball_size::BallSizeChallengePlugin;
+use boomerang::BoomerangPlugin;
+use brick_breaker::BrickBreakerPlugin;
+use burst::BurstShotPlugin;
+use camera::CameraPlugin;
+use center_paddle::ObstaclePaddlePlugin;
+use challenge_mode::ChallengeModePlugin;
+use changelog::ChangelogPlugin;
+use checkpoint::CheckpointPlugin;
+use collision::Physics2DPlugin;
+use collision_audio::CollisionAudioPlugin;
+use color_blind::ColorBlindPlugin;
+use confetti::ConfettiPlugin;
+#[cfg(debug_assertions)]
+use debug_overlay::DebugOverlayPlugin;
+use double_width::DoubleWidthPlugin;
+use edge_glow::ScreenEdgeGlowPlugin;
+use energy::EnergyBarPlugin;
+use event_log::EventLogPlugin;
+use game_state::GameStatePlugin;
+use ghost::BallGhostPlugin;
+use glitch::GlitchPlugin;
+#[cfg(debug_assertions)]
+use god_mode::GodModePlugin;
+use gravity::GravityPlugin;
+use gravity_well::GravityWellPlugin;
+use gutter::{
+    break_gutters, Gutter, GutterAssets, GutterKind, GutterPlugin, Health, GUTTER_HEIGHT,
+};
+use handicap::HandicapPlugin;
+#[cfg(all(target_os = "linux", feature = "haptic"))]
+use haptic::HapticPlugin;
+use heat_map::HeatMapPlugin;
+use impact::ImpactRingPlugin;
+use input_recorder::InputRecorderPlugin;
+use key_bindings::KeyBindingsPlugin;
+use leaderboard::LeaderboardPlugin;
+use localization::LocalizationPlugin;
+use match_history::MatchHistoryPlugin;
+use monitor::MonitorSelectPlugin;
+use mouse_mode::MouseModePlugin;
+use moving_gutters::MovingGuttersPlugin;
+use multiball::MultiballPlugin;
+use music::SoundtrackPlugin;
+use narrator::NarratorPlugin;
+use paddle::{
+    apply_paddle_friction, handle_player_input, move_paddles, Ai, Paddle, PaddleAssets,
+    PaddleBundle, PaddleHeightModifiers, PaddlePlugin, PADDLE_HEIGHT, PADDLE_PADDING, PADDLE_SPEED,
+    PADDLE_WIDTH,
+};
+use paddle_customization::PaddleCustomizationPlugin;
+use paddle_grow::PaddleGrowPlugin;
+use paddle_resize::PaddleResizePlugin;
+use portal::PortalPlugin;
+use post_score_freeze::PostScoreFreezePlugin;
+use powerups::PowerupPlugin;
+use profile::PlayerProfilePlugin;
+use projectile::ProjectilePlugin;
+use racket_spin::RacketSpinPlugin;
+use rebound_paddle::ReboundPaddlePlugin;
+use replay::SlowMotionReplayPlugin;
+use rotating_playfield::RotatingPlayfieldPlugin;
+use save_state::SaveStatePlugin;
+use score::{detect_scoring, update_score, Score, ScorePlugin, Scored};
+use score_flash::ScoreFlashPlugin;
+#[cfg(debug_assertions)]
+use screenshot::ScreenshotOnScorePlugin;
+use serve::ServeSelectorPlugin;
+use simulation_speed::SimulationSpeedPlugin;
+use slippery_floor::SlipperyFloorPlugin;
+use slow_motion::SlowMotionOnNearMissPlugin;
+use slow_zone::SlowZonePlugin;
+use speed_lines::SpeedLinesPlugin;
+use splash::SplashScreenPlugin;
+use streak::StreakPlugin;
+use target_zone::TargetZonePlugin;
+use team::TeamPlugin;
+use tennis::TennisSetsPlugin;
+use toast::ToastPlugin;
+use tournament::TournamentPlugin;
+use trail::BallTrailPlugin;
+use tutorial::TutorialPlugin;
+use ui::UiPlugin;
+#[cfg(debug_assertions)]
+use undo::UndoPlugin;
+use victory_lap::VictoryLapPlugin;
+#[cfg(feature = "video_capture")]
+use video_capture::VideoCapturePlugin;
+use visual_novel::VisualNovelPlugin;
+use wall_breaker::WallBreakerPlugin;
+use wormhole::WormholePlugin;
+use zipline::ZiplinePlugin;
+use zoom_camera::ZoomCameraPlugin;
+
+// What it takes to play a match from outside, as the benches do
+pub use ball::Ball;
+pub use game_state::{GameState, MatchResult};
+pub use key_bindings::KeyBindings;
+pub use paddle::Player;
+pub use rally::RallySpeedPlugin;
+pub use score::Scorer;
+
+// gameplay was tuned for one physics step per frame at 60fps
+const BASE_TIMESTEP_HZ: f64 = 60.;
+
+// The components every part of the game moves and collides with,
+// the plugins below own everything else
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct Shape(Vec2);
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct Velocity(Vec2);
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct Position(Vec2);
+
+// The game itself, a match from the first serve to the game over
+// screen. Everything else is a feature around it, so this is all a
+// headless app needs to play.
+pub struct PongPlugin;
+
+impl Plugin for PongPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            GameStatePlugin,
+            CameraPlugin,
+            BallPlugin,
+            PaddlePlugin,
+            AiPlugin,
+            GutterPlugin,
+            ScorePlugin,
+            UiPlugin,
+        ))
+        .insert_resource(Time::<Fixed>::from_hz(BASE_TIMESTEP_HZ))
+        .add_systems(
+            Update,
+            pause_game.run_if(in_state(GameState::Playing).or_else(in_state(GameState::Paused))),
+        );
+    }
+}
+
+// The whole game, in a window
+pub fn run() {
+    let mut app = App::new();
+    // plugin tuples max out at 15, so features are grouped
+    app.add_plugins((
+        DefaultPlugins,
+        PongPlugin,
+        // presentation
+        (
+            SoundtrackPlugin,
+            ScreenEdgeGlowPlugin,
+            ZoomCameraPlugin,
+            CollisionAudioPlugin,
+            ImpactRingPlugin,
+            ConfettiPlugin,
+            BallTrailPlugin,
+            GlitchPlugin,
+            SlowMotionOnNearMissPlugin,
+            ScoreFlashPlugin,
+            BallGhostPlugin,
+            SpeedLinesPlugin,
+            SlowMotionReplayPlugin,
+            NarratorPlugin,
+            VisualNovelPlugin,
+        ),
+        // gameplay
+        (
+            MultiballPlugin,
+            HandicapPlugin,
+            BallSizeChallengePlugin,
+            TargetZonePlugin,
+            SlipperyFloorPlugin,
+            PaddleResizePlugin,
+            ProjectilePlugin,
+            EnergyBarPlugin,
+            PaddleAbsorbPlugin,
+            BurstShotPlugin,
+            RallySpeedPlugin,
+            AiMistakesPlugin,
+        ),
+        // the field itself
+        (
+            Physics2DPlugin,
+            MovingGuttersPlugin,
+            SlowZonePlugin,
+            WallBreakerPlugin,
+        ),
+        // powerups and what they do
+        (
+            PowerupPlugin,
+            RacketSpinPlugin,
+            PaddleGrowPlugin,
+            WormholePlugin,
+            ReboundPaddlePlugin,
+            GravityWellPlugin,
+            DoubleWidthPlugin,
+        ),
+        // match flow
+        (
+            SplashScreenPlugin,
+            ServeSelectorPlugin,
+            PostScoreFreezePlugin,
+            StreakPlugin,
+            VictoryLapPlugin,
+        ),
+        // alternative game modes
+        (
+            MouseModePlugin,
+            ChallengeModePlugin,
+            BoomerangPlugin,
+            GravityPlugin,
+            PortalPlugin,
+            TennisSetsPlugin,
+            RotatingPlayfieldPlugin,
+            ObstaclePaddlePlugin,
+            BrickBreakerPlugin,
+            TeamPlugin,
+            ZiplinePlugin,
+        ),
+        // player facing tools
+        (
+            SaveStatePlugin,
+            CheckpointPlugin,
+            SimulationSpeedPlugin,
+            EventLogPlugin,
+            LocalizationPlugin,
+            MonitorSelectPlugin,
+            ToastPlugin,
+            HeatMapPlugin,
+            ColorBlindPlugin,
+            InputRecorderPlugin,
+        ),
+        // menus and screens
+        (
+            PlayerProfilePlugin,
+            TutorialPlugin,
+            KeyBindingsPlugin,
+            LeaderboardPlugin,
+            PaddleCustomizationPlugin,
+            ChangelogPlugin,
+            TournamentPlugin,
+            MatchHistoryPlugin,
+        ),
+    ));
+
+    // developer tools are left out of release builds
+    #[cfg(debug_assertions)]
+    app.add_plugins((
+        UndoPlugin,
+        DebugOverlayPlugin,
+        ScreenshotOnScorePlugin,
+        GodModePlugin,
+    ));
+
+    #[cfg(feature = "video_capture")]
+    app.add_plugins(VideoCapturePlugin);
+
+    #[cfg(all(target_os = "linux", feature = "haptic"))]
+    app.add_plugins(HapticPlugin);
+
+    app.run();
+}
+
+// Pausing virtual time stops the fixed physics steps with it,
+// along with every feature's timers
+fn pause_game(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    bindings: Option<Res<KeyBindings>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    let pause = bindings.map_or(KeyBindings::default().pause, |bindings| bindings.pause);
+    if keyboard_input.just_pressed(pause) {
+        if *state.get() == GameState::Paused {
+            time.unpause();
+            next_state.set(GameState::Playing);
+        } else {
+            time.pause();
+            next_state.set(GameState::Paused);
+        }
+        debug!("Paused: {}", time.is_paused());
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::settings;
+
+#[derive(Resource, Serialize, Deserialize, Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Fr];
+
+    fn index(self) -> usize {
+        match self {
+            Locale::En => 0,
+            Locale::Fr => 1,
+        }
+    }
+}
+
+// key, then one string per locale in `Locale::ALL` order
+const STRINGS: &[(&str, [&str; 2])] = &[
+    ("player", ["Player", "Joueur"]),
+    ("ai", ["AI", "IA"]),
+    ("pause", ["Pause", "Pause"]),
+    ("game_over", ["Game Over", "Partie terminée"]),
+    ("player_wins", ["Player wins", "Le joueur gagne"]),
+    ("ai_wins", ["AI wins", "L'IA gagne"]),
+    ("winner", ["Winner!", "Victoire !"]),
+    ("tournament", ["Tournament", "Tournoi"]),
+    ("round_one", ["Round one", "Premier tour"]),
+    ("final", ["Final", "Finale"]),
+    ("beaten", ["Beaten", "Battu"]),
+    (
+        "tournament_hint",
+        [
+            "Press Space for the next match",
+            "Appuyez sur Espace pour le match suivant",
+        ],
+    ),
+    (
+        "tournament_champion",
+        [
+            "{name} wins the tournament!",
+            "{name} remporte le tournoi !",
+        ],
+    ),
+    ("multiball", ["MULTIBALL!", "MULTIBALLE !"]),
+    ("assist", ["ASSIST", "AIDE"]),
+    ("match_summary", ["Match summary", "Résumé du match"]),
+    ("player_scored", ["Player scored", "Le joueur marque"]),
+    ("ai_scored", ["AI scored", "L'IA marque"]),
+    ("paddle_hit", ["Paddle hit", "Renvoi"]),
+    (
+        "rally_broken",
+        [
+            "Rally of {hits} hits broken",
+            "Échange de {hits} coups rompu",
+        ],
+    ),
+    (
+        "tutorial_welcome",
+        [
+            "Welcome to Pong!\nPress Space to continue",
+            "Bienvenue dans Pong !\nAppuyez sur Espace pour continuer",
+        ],
+    ),
+    (
+        "tutorial_move",
+        [
+            "Move your paddle up with Y and down with N",
+            "Déplacez votre raquette vers le haut avec Y et vers le bas avec N",
+        ],
+    ),
+    (
+        "tutorial_serve",
+        [
+            "Press Space to serve the ball",
+            "Appuyez sur Espace pour servir la balle",
+        ],
+    ),
+    (
+        "tutorial_score",
+        [
+            "Get the ball past the right paddle to score",
+            "Faites passer la balle derrière la raquette de droite pour marquer",
+        ],
+    ),
+    (
+        "tutorial_complete",
+        [
+            "You're ready!\nPress Space to play",
+            "Vous êtes prêt !\nAppuyez sur Espace pour jouer",
+        ],
+    ),
+    ("profile", ["Profile", "Profil"]),
+    ("bonus", ["BONUS", "BONUS"]),
+    ("angle_bonus", ["ANGLE BONUS ×2!", "BONUS D'ANGLE ×2 !"]),
+    ("streak", ["3-STREAK!", "3 D'AFFILÉE !"]),
+    ("on_fire", ["ON FIRE!", "EN FEU !"]),
+    ("profile_name", ["Name", "Nom"]),
+    ("paddle_shape", ["Paddle shape", "Forme de la raquette"]),
+    ("shape_rectangle", ["Rectangle", "Rectangle"]),
+    ("shape_oval", ["Oval", "Ovale"]),
+    ("shape_hexagon", ["Hexagon", "Hexagone"]),
+    (
+        "paddle_shape_help",
+        [
+            "< > to choose, Enter to confirm, U to close",
+            "< > pour choisir, Entrée pour valider, U pour fermer",
+        ],
+    ),
+    ("leaderboard", ["Leaderboard", "Classement"]),
+    ("rally_avg", ["Rally avg", "Échange moyen"]),
+    ("best_rally", ["Best rally", "Meilleur échange"]),
+    ("this_session", ["This session", "Cette session"]),
+    ("all_time", ["All time", "Historique"]),
+    ("match_history", ["Match history", "Historique des matchs"]),
+    ("matches_played", ["Matches played", "Matchs joués"]),
+    ("win_rate", ["Win rate", "Taux de victoire"]),
+    ("key_bindings", ["Key bindings", "Touches"]),
+    ("bind_player_up", ["Player up", "Joueur haut"]),
+    ("bind_player_down", ["Player down", "Joueur bas"]),
+    ("bind_ai_up", ["AI up", "IA haut"]),
+    ("bind_ai_down", ["AI down", "IA bas"]),
+    ("bind_pause", ["Pause", "Pause"]),
+    ("bind_serve", ["Serve", "Service"]),
+    ("bind_absorb", ["Absorb", "Absorber"]),
+    (
+        "press_to_rebind",
+        ["Press to rebind", "Cliquer pour changer"],
+    ),
+    (
+        "press_a_key",
+        ["Press a key...", "Appuyez sur une touche..."],
+    ),
+    (
+        "binding_taken",
+        [
+            "That key is already bound",
+            "Cette touche est déjà utilisée",
+        ],
+    ),
+    (
+        "resume_prompt",
+        [
+            "Resume saved match?\nEnter to resume, Backspace to start over",
+            "Reprendre le match sauvegardé ?\nEntrée pour reprendre, Retour arrière pour recommencer",
+        ],
+    ),
+    (
+        "changelog_help",
+        [
+            "Scroll to read, Escape to close",
+            "Faites défiler pour lire, Échap pour fermer",
+        ],
+    ),
+    (
+        "menu_start",
+        [
+            "Press Space to play, F for a tournament, 1 for match history, F2 to edit your profile, J for what's new",
+            "Appuyez sur Espace pour jouer, F pour un tournoi, 1 pour l'historique, F2 pour modifier votre profil, J pour les nouveautés",
+        ],
+    ),
+    ("mode_on", ["on", "activé"]),
+    ("mode_off", ["off", "désactivé"]),
+    ("mode_ball_size", ["Growing ball", "Balle grandissante"]),
+    ("mode_boomerang", ["Boomerang", "Boomerang"]),
+    ("mode_brick_breaker", ["Brick breaker", "Casse-briques"]),
+    ("mode_challenge", ["Scoring zones", "Zones de score"]),
+    ("mode_gravity", ["Gravity", "Gravité"]),
+    ("mode_moving_gutters", ["Moving gutters", "Buts mobiles"]),
+    ("mode_obstacle_paddle", ["Centre paddle", "Raquette centrale"]),
+    ("mode_portal", ["Portals", "Portails"]),
+    ("mode_rotating_playfield", ["Rotating field", "Terrain tournant"]),
+    ("mode_streak_powerups", ["Streak powerups", "Bonus de série"]),
+    ("mode_tennis", ["Tennis sets", "Sets de tennis"]),
+    ("mode_visual_novel", ["Visual novel", "Roman visuel"]),
+    ("mode_zipline", ["Zipline", "Tyrolienne"]),
+    (
+        "rematch_or_quit",
+        [
+            "Space for a rematch, Escape to quit",
+            "Espace pour une revanche, Échap pour quitter",
+        ],
+    ),
+    (
+        "profile_colour",
+        ["Colour: < > to change", "Couleur : < > pour changer"],
+    ),
+    (
+        "profile_help",
+        [
+            "Enter to save, F2 to close",
+            "Entrée pour enregistrer, F2 pour fermer",
+        ],
+    ),
+];
+
+fn table() -> &'static HashMap<&'static str, [&'static str; 2]> {
+    static TABLE: OnceLock<HashMap<&'static str, [&'static str; 2]>> = OnceLock::new();
+    TABLE.get_or_init(|| STRINGS.iter().copied().collect())
+}
+
+// Looks up a UI string, falling back to the key itself when missing
+pub fn t(key: &'static str, locale: &Locale) -> &'static str {
+    table()
+        .get(key)
+        .map(|strings| strings[locale.index()])
+        .unwrap_or(key)
+}
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(settings::load().locale)
+            .add_systems(Update, toggle_locale);
+    }
+}
+
+fn toggle_locale(keyboard_input: Res<ButtonInput<KeyCode>>, mut locale: ResMut<Locale>) {
+    if keyboard_input.just_pressed(KeyCode::F6) {
+        *locale = Locale::ALL[(locale.index() + 1) % Locale::ALL.len()];
+        let saved = *locale;
+        settings::update(|settings| settings.locale = saved);
+        info!("Locale: {:?}", *locale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every key the game looks up, checked for translations by the
+    // tests. Add new keys here along with their `t` calls.
+    const ALL_KEYS: &[&str] = &[
+        "ai_scored",
+        "ai_wins",
+        "all_time",
+        "angle_bonus",
+        "assist",
+        "beaten",
+        "best_rally",
+        "bind_absorb",
+        "bind_ai_down",
+        "bind_ai_up",
+        "bind_pause",
+        "bind_player_down",
+        "bind_player_up",
+        "bind_serve",
+        "binding_taken",
+        "bonus",
+        "changelog_help",
+        "final",
+        "game_over",
+        "key_bindings",
+        "leaderboard",
+        "match_history",
+        "match_summary",
+        "matches_played",
+        "menu_start",
+        "mode_ball_size",
+        "mode_boomerang",
+        "mode_brick_breaker",
+        "mode_challenge",
+        "mode_gravity",
+        "mode_moving_gutters",
+        "mode_obstacle_paddle",
+        "mode_off",
+        "mode_on",
+        "mode_portal",
+        "mode_rotating_playfield",
+        "mode_streak_powerups",
+        "mode_tennis",
+        "mode_visual_novel",
+        "mode_zipline",
+        "multiball",
+        "on_fire",
+        "paddle_hit",
+        "paddle_shape",
+        "paddle_shape_help",
+        "pause",
+        "player_scored",
+        "player_wins",
+        "press_a_key",
+        "press_to_rebind",
+        "profile",
+        "profile_colour",
+        "profile_help",
+        "profile_name",
+        "rally_avg",
+        "rally_broken",
+        "rematch_or_quit",
+        "resume_prompt",
+        "round_one",
+        "shape_hexagon",
+        "shape_oval",
+        "shape_rectangle",
+        "streak",
+        "this_session",
+        "tournament",
+        "tournament_champion",
+        "tournament_hint",
+        "tutorial_complete",
+        "tutorial_move",
+        "tutorial_score",
+        "tutorial_serve",
+        "tutorial_welcome",
+        "win_rate",
+        "winner",
+    ];
+
+    #[test]
+    fn every_key_is_translated() {
+        for locale in Locale::ALL {
+            for key in ALL_KEYS {
+                let text = t(key, &locale);
+                // `t` falls back to the key itself when it's missing
+                assert_ne!(text, *key, "{key} has no {locale:?} translation");
+                assert!(!text.is_empty(), "{key} is empty in {locale:?}");
+            }
+        }
+    }
+}
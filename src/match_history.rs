@@ -0,0 +1,174 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use rusqlite::Connection;
+
+use crate::ai::{AiDifficulty, AiOpponent};
+use crate::db::{self, MatchRecord};
+use crate::game_state::{despawn_screen, reset_resource, GameState, InMatch, MatchResult};
+use crate::localization::{t, Locale};
+use crate::{PaddleHit, Scored};
+
+// The database every finished match goes into. Left out when it
+// couldn't be opened, and the game plays on without a history.
+// A `Connection` can't be shared between threads, so this is a
+// non-send resource, only ever touched from the main thread.
+struct MatchHistory(Connection);
+
+// What the database keeps about the match being played
+#[derive(Resource, Default)]
+struct MatchStats {
+    // time spent paused doesn't count
+    duration_secs: f32,
+    paddle_hits: u32,
+    current_rally: u32,
+    longest_rally: u32,
+}
+
+#[derive(Component)]
+struct StatsScreen;
+
+pub struct MatchHistoryPlugin;
+
+impl Plugin for MatchHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        match db::open() {
+            Ok(connection) => {
+                app.insert_non_send_resource(MatchHistory(connection));
+            }
+            Err(err) => warn!("Failed to open the match history, not keeping one: {err}"),
+        }
+        app.init_resource::<MatchStats>()
+            .add_systems(OnEnter(InMatch), reset_resource::<MatchStats>)
+            .add_systems(OnEnter(GameState::GameOver), save_match)
+            .add_systems(OnExit(GameState::Menu), despawn_screen::<StatsScreen>)
+            .add_systems(
+                Update,
+                (
+                    track_match.run_if(in_state(GameState::Playing)),
+                    toggle_stats_screen
+                        .run_if(in_state(GameState::Menu))
+                        .run_if(input_just_pressed(KeyCode::Digit1)),
+                ),
+            );
+    }
+}
+
+fn track_match(
+    mut stats: ResMut<MatchStats>,
+    mut paddle_hits: EventReader<PaddleHit>,
+    mut scored_events: EventReader<Scored>,
+    time: Res<Time>,
+) {
+    stats.duration_secs += time.delta_seconds();
+
+    let hits = paddle_hits.read().count() as u32;
+    stats.paddle_hits += hits;
+    stats.current_rally += hits;
+    stats.longest_rally = stats.longest_rally.max(stats.current_rally);
+    if scored_events.read().last().is_some() {
+        stats.current_rally = 0;
+    }
+}
+
+fn save_match(
+    history: Option<NonSend<MatchHistory>>,
+    result: Option<Res<MatchResult>>,
+    stats: Res<MatchStats>,
+    difficulty: Res<AiDifficulty>,
+    ai_opponent: Res<AiOpponent>,
+) {
+    let (Some(history), Some(result)) = (history, result) else {
+        return;
+    };
+
+    let record = MatchRecord {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs() as i64),
+        player_score: result.player,
+        ai_score: result.ai,
+        duration_secs: stats.duration_secs,
+        difficulty: if ai_opponent.0 {
+            difficulty.as_str()
+        } else {
+            "two_player"
+        },
+        longest_rally: stats.longest_rally,
+        paddle_hits: stats.paddle_hits,
+    };
+    if let Err(err) = db::insert_match(&history.0, &record) {
+        error!("Failed to save the match to the history: {err}");
+    }
+}
+
+fn toggle_stats_screen(
+    mut commands: Commands,
+    screens: Query<Entity, With<StatsScreen>>,
+    history: Option<NonSend<MatchHistory>>,
+    locale: Res<Locale>,
+) {
+    if !screens.is_empty() {
+        for entity in &screens {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    let summary = match history.map(|history| db::summary(&history.0)) {
+        Some(Ok(summary)) => summary,
+        Some(Err(err)) => {
+            error!("Failed to read the match history: {err}");
+            return;
+        }
+        None => return,
+    };
+    let win_rate = if summary.matches == 0 {
+        0.
+    } else {
+        summary.wins as f32 / summary.matches as f32 * 100.
+    };
+    let lines = [
+        format!("{}: {}", t("matches_played", &locale), summary.matches),
+        format!("{}: {win_rate:.0}%", t("win_rate", &locale)),
+        format!("{}: {:.1}", t("rally_avg", &locale), summary.rally_avg),
+    ];
+
+    let text = |value: &str, font_size: f32| {
+        TextBundle::from_section(
+            value,
+            TextStyle {
+                font_size,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+    };
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(16.),
+                    ..default()
+                },
+                background_color: Color::BLACK.into(),
+                // over the menu screen
+                z_index: ZIndex::Global(95),
+                ..default()
+            },
+            StatsScreen,
+        ))
+        .with_children(|screen| {
+            screen.spawn(text(t("match_history", &locale), 60.));
+            for line in &lines {
+                screen.spawn(text(line, 28.));
+            }
+        });
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use bevy::window::{MonitorSelection, PrimaryWindow, WindowMoved, WindowPosition};
+use bevy::winit::WinitWindows;
+
+use crate::{settings, Gutter, GutterAssets, Shape, GUTTER_HEIGHT};
+
+// Monitors by name, in the order `MonitorSelection::Index` counts them.
+// Filled in once winit has created the primary window.
+#[derive(Resource, Default)]
+struct MonitorSelect {
+    monitors: Vec<String>,
+    selected: Option<usize>,
+}
+
+pub struct MonitorSelectPlugin;
+
+impl Plugin for MonitorSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MonitorSelect>().add_systems(
+            Update,
+            (
+                list_monitors,
+                cycle_monitor.after(list_monitors),
+                refit_gutters.run_if(on_event::<WindowMoved>()),
+            ),
+        );
+    }
+}
+
+fn list_monitors(
+    winit_windows: NonSend<WinitWindows>,
+    mut monitor_select: ResMut<MonitorSelect>,
+    mut window: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+) {
+    if !monitor_select.monitors.is_empty() {
+        return;
+    }
+    let Ok((entity, mut window)) = window.get_single_mut() else {
+        return;
+    };
+    let Some(winit_window) = winit_windows.get_window(entity) else {
+        return;
+    };
+
+    monitor_select.monitors = winit_window
+        .available_monitors()
+        .enumerate()
+        .map(|(index, monitor)| {
+            monitor
+                .name()
+                .unwrap_or_else(|| format!("Monitor {}", index + 1))
+        })
+        .collect();
+    info!("Monitors: {:?}", monitor_select.monitors);
+
+    // move to the monitor picked last time, if it's still plugged in
+    if let Some(saved) = settings::load().monitor {
+        monitor_select.selected = monitor_select
+            .monitors
+            .iter()
+            .position(|name| *name == saved);
+        if let Some(index) = monitor_select.selected {
+            window.position = WindowPosition::Centered(MonitorSelection::Index(index));
+        }
+    }
+}
+
+// There is no settings screen yet, so F8 steps through the monitors
+fn cycle_monitor(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut monitor_select: ResMut<MonitorSelect>,
+    mut window: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F8) || monitor_select.monitors.is_empty() {
+        return;
+    }
+
+    let index = monitor_select
+        .selected
+        .map_or(0, |index| (index + 1) % monitor_select.monitors.len());
+    monitor_select.selected = Some(index);
+
+    if let Ok(mut window) = window.get_single_mut() {
+        window.position = WindowPosition::Centered(MonitorSelection::Index(index));
+    }
+
+    let name = monitor_select.monitors[index].clone();
+    info!("Moving to monitor {name}");
+    settings::update(|settings| settings.monitor = Some(name));
+}
+
+// A new monitor may come with a new resolution, so the gutters are
+// rebuilt to span the window again. Only done when the size actually
+// changed, so dragging the window around can't heal broken gutters.
+fn refit_gutters(
+    mut commands: Commands,
+    gutters: Query<(Entity, &Shape), With<Gutter>>,
+    gutter_assets: Res<GutterAssets>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    let window_width = window.resolution.width();
+    let window_height = window.resolution.height();
+
+    if gutters.iter().all(|(_, shape)| shape.0.x == window_width) {
+        return;
+    }
+
+    info!("Refitting gutters to {window_width}x{window_height}");
+    for (entity, _) in &gutters {
+        commands.entity(entity).despawn();
+    }
+    meshes.insert(
+        &gutter_assets.mesh,
+        Rectangle::new(window_width, GUTTER_HEIGHT).into(),
+    );
+    gutter_assets.spawn_gutters(&mut commands, window_width, window_height);
+}
@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+use bevy::window::{CursorMoved, PrimaryWindow};
+
+use crate::game_state::GameState;
+use crate::{handle_player_input, move_paddles, settings, Player, Position, Velocity};
+
+const CROSSHAIR_SIZE: f32 = 16.;
+const CROSSHAIR_THICKNESS: f32 = 2.;
+
+// The player's paddle follows the mouse instead of the keyboard.
+// `target_y` is the cursor's last height in world space.
+#[derive(Resource)]
+struct MouseMode {
+    enabled: bool,
+    target_y: Option<f32>,
+}
+
+#[derive(Component)]
+struct Crosshair;
+
+pub struct MouseModePlugin;
+
+impl Plugin for MouseModePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MouseMode {
+            enabled: settings::load().mouse_mode,
+            target_y: None,
+        })
+        .add_systems(Startup, spawn_crosshair)
+        .add_systems(
+            Update,
+            (
+                toggle_mouse_mode,
+                track_cursor.after(toggle_mouse_mode),
+                show_crosshair.after(toggle_mouse_mode),
+            ),
+        )
+        // replaces keyboard movement, then `move_paddles` clamps it to the field
+        .add_systems(
+            FixedUpdate,
+            follow_mouse
+                .after(handle_player_input)
+                .before(move_paddles)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+fn spawn_crosshair(mut commands: Commands) {
+    let color = Color::srgba(1., 1., 1., 0.8);
+    commands
+        .spawn((SpatialBundle::HIDDEN_IDENTITY, Crosshair))
+        .with_children(|parent| {
+            for size in [
+                Vec2::new(CROSSHAIR_SIZE, CROSSHAIR_THICKNESS),
+                Vec2::new(CROSSHAIR_THICKNESS, CROSSHAIR_SIZE),
+            ] {
+                parent.spawn(SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(size),
+                        ..default()
+                    },
+                    ..default()
+                });
+            }
+        });
+}
+
+// There is no settings screen yet, so M toggles mouse mode
+fn toggle_mouse_mode(keyboard_input: Res<ButtonInput<KeyCode>>, mut mouse_mode: ResMut<MouseMode>) {
+    if keyboard_input.just_pressed(KeyCode::KeyM) {
+        mouse_mode.enabled = !mouse_mode.enabled;
+        let saved = mouse_mode.enabled;
+        settings::update(|settings| settings.mouse_mode = saved);
+        info!("Mouse mode: {}", mouse_mode.enabled);
+    }
+}
+
+fn track_cursor(
+    mut mouse_mode: ResMut<MouseMode>,
+    mut cursor_events: EventReader<CursorMoved>,
+    camera: Query<(&Camera, &GlobalTransform)>,
+    mut crosshair: Query<&mut Transform, With<Crosshair>>,
+) {
+    let Some(cursor) = cursor_events.read().last() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera.get_single() else {
+        return;
+    };
+    // the camera zooms and shakes, so go through it rather than the window
+    let Some(world_position) = camera.viewport_to_world_2d(camera_transform, cursor.position)
+    else {
+        return;
+    };
+
+    mouse_mode.target_y = Some(world_position.y);
+    if let Ok(mut transform) = crosshair.get_single_mut() {
+        // above everything else
+        transform.translation = world_position.extend(10.);
+    }
+}
+
+fn show_crosshair(
+    mouse_mode: Res<MouseMode>,
+    mut crosshair: Query<&mut Visibility, With<Crosshair>>,
+    mut window: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !mouse_mode.is_changed() {
+        return;
+    }
+
+    if let Ok(mut visibility) = crosshair.get_single_mut() {
+        *visibility = if mouse_mode.enabled {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+    // the cursor moving changes `MouseMode` too, only touch
+    // the window when it really has to change
+    if let Ok(mut window) = window.get_single_mut() {
+        if window.cursor.visible == mouse_mode.enabled {
+            window.cursor.visible = !mouse_mode.enabled;
+        }
+    }
+}
+
+fn follow_mouse(
+    mouse_mode: Res<MouseMode>,
+    mut paddle: Query<(&mut Position, &mut Velocity), With<Player>>,
+) {
+    if !mouse_mode.enabled {
+        return;
+    }
+
+    if let Ok((mut position, mut velocity)) = paddle.get_single_mut() {
+        velocity.0.y = 0.;
+        if let Some(target_y) = mouse_mode.target_y {
+            position.0.y = target_y;
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::game_state::{AddGameMode, GameState};
+use crate::{move_paddles, Gutter, GutterKind, Position};
+
+const GUTTER_AMPLITUDE: f32 = 30.;
+// radians per second
+const GUTTER_FREQUENCY: f32 = 1.5;
+
+// Game variant where the gutters swing in and out of the play field
+#[derive(Resource, Default)]
+struct MovingGutters(bool);
+
+pub struct MovingGuttersPlugin;
+
+impl Plugin for MovingGuttersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MovingGutters>()
+            .add_game_mode(KeyCode::KeyG, "mode_moving_gutters", toggle_moving_gutters)
+            .add_systems(
+                FixedUpdate,
+                (assign_gutter_kinds, move_gutters.after(assign_gutter_kinds))
+                    .before(move_paddles)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn toggle_moving_gutters(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut moving: ResMut<MovingGutters>,
+) {
+    // Ctrl+G is god mode in debug builds
+    if keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+    moving.0 = !moving.0;
+    info!("Moving gutters: {}", moving.0);
+}
+
+// Gutters are respawned every point, so their kind is set
+// on the fresh ones as well as when the variant changes
+fn assign_gutter_kinds(
+    moving: Res<MovingGutters>,
+    mut gutters: Query<(Ref<Gutter>, &mut GutterKind, &mut Position)>,
+) {
+    for (gutter, mut kind, mut position) in &mut gutters {
+        if !gutter.is_added() && !moving.is_changed() {
+            continue;
+        }
+
+        match (&*kind, moving.0) {
+            (GutterKind::Static, true) => {
+                // they swing inwards from where they normally sit, so
+                // they never leave a gap at the edge of the window
+                let inwards = -position.0.y.signum();
+                *kind = GutterKind::Oscillating {
+                    base_y: position.0.y + inwards * GUTTER_AMPLITUDE,
+                    amplitude: GUTTER_AMPLITUDE,
+                    frequency: GUTTER_FREQUENCY,
+                    // top and bottom open and close together
+                    phase: if position.0.y > 0. { 0. } else { PI },
+                };
+            }
+            (
+                GutterKind::Oscillating {
+                    base_y, amplitude, ..
+                },
+                false,
+            ) => {
+                position.0.y = base_y + base_y.signum() * amplitude;
+                *kind = GutterKind::Static;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn move_gutters(mut gutters: Query<(&GutterKind, &mut Position)>, time: Res<Time>) {
+    for (kind, mut position) in &mut gutters {
+        if let GutterKind::Oscillating {
+            base_y,
+            amplitude,
+            frequency,
+            phase,
+        } = kind
+        {
+            position.0.y = base_y + amplitude * (time.elapsed_seconds() * frequency + phase).sin();
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use rand::random;
+
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::localization::{t, Locale};
+use crate::{random_serve_velocity, BallAssets, BallBundle, BallFrozen, Scored};
+
+// how long a rally has to go without a score before a second ball joins
+const MULTIBALL_DELAY_SECS: f32 = 30.;
+const MULTIBALL_BANNER_SECS: f32 = 2.;
+
+#[derive(Resource)]
+struct RallyTimer(Timer);
+
+impl Default for RallyTimer {
+    fn default() -> Self {
+        RallyTimer(Timer::from_seconds(MULTIBALL_DELAY_SECS, TimerMode::Once))
+    }
+}
+
+#[derive(Component)]
+struct MultiballBanner(Timer);
+
+pub struct MultiballPlugin;
+
+impl Plugin for MultiballPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RallyTimer>()
+            .add_systems(OnEnter(InMatch), reset_resource::<RallyTimer>)
+            .add_systems(
+                Update,
+                (
+                    (reset_rally_timer, tick_rally_timer.after(reset_rally_timer))
+                        .run_if(in_state(GameState::Playing)),
+                    despawn_multiball_banner,
+                ),
+            );
+    }
+}
+
+fn reset_rally_timer(mut rally_timer: ResMut<RallyTimer>, mut events: EventReader<Scored>) {
+    if events.read().last().is_some() {
+        rally_timer.0.reset();
+    }
+}
+
+fn tick_rally_timer(
+    mut commands: Commands,
+    mut rally_timer: ResMut<RallyTimer>,
+    ball_assets: Res<BallAssets>,
+    ball_frozen: Res<BallFrozen>,
+    locale: Res<Locale>,
+    time: Res<Time>,
+) {
+    // a held ball isn't rallying
+    if ball_frozen.0 {
+        return;
+    }
+
+    // the timer is one-shot so only one extra ball is fired per rally
+    if !rally_timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    info!("Multiball!");
+
+    let x_dir = if random::<bool>() { 1. } else { -1. };
+    let velocity = random_serve_velocity(x_dir);
+    commands.spawn((
+        BallBundle::new(velocity.x, velocity.y),
+        ball_assets.bundle(),
+        // the match's one ball is reset instead
+        StateScoped(InMatch),
+    ));
+
+    commands.spawn((
+        TextBundle::from_section(
+            t("multiball", &locale),
+            TextStyle {
+                font_size: 60.,
+                color: Color::srgb_u8(255, 200, 50),
+                ..default()
+            },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(40.),
+            left: Val::Percent(35.),
+            ..default()
+        }),
+        MultiballBanner(Timer::from_seconds(MULTIBALL_BANNER_SECS, TimerMode::Once)),
+        StateScoped(InMatch),
+    ));
+}
+
+fn despawn_multiball_banner(
+    mut commands: Commands,
+    mut banners: Query<(Entity, &mut MultiballBanner)>,
+    time: Res<Time>,
+) {
+    for (entity, mut banner) in &mut banners {
+        if banner.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
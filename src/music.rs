@@ -0,0 +1,155 @@
+use bevy::audio::{PlaybackMode, Volume};
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::simulation_speed::SimulationSpeed;
+use crate::{Ball, Score, Velocity, MAX_BALL_SPEED, MIN_BALL_SPEED};
+
+// score difference at which the intense layer is fully faded in
+const MAX_INTENSITY_SCORE_DIFF: f32 = 5.;
+// how quickly the blend follows the score, in blend units per second
+const BLEND_SPEED: f32 = 0.5;
+// the music fades out over this long when a match ends
+const FADE_OUT_SECS: f32 = 2.;
+// extra tempo with the ball at top speed
+const MAX_TEMPO_BOOST: f32 = 0.4;
+// fraction of the gap to the target tempo closed per second
+const TEMPO_LERP_SPEED: f32 = 3.;
+// how strongly slowed down time drags the music down with it,
+// 0.2× simulation speed plays at roughly 0.6× tempo
+const SLOW_MOTION_TEMPO_EXPONENT: f32 = 0.3;
+
+#[derive(Resource)]
+struct DynamicMusicLayer {
+    base: Handle<AudioSource>,
+    intense: Handle<AudioSource>,
+    blend: f32,
+    // playback speed, pitch and tempo together
+    tempo: f32,
+    // overall volume, faded out on the game over screen
+    volume: f32,
+    playing: bool,
+}
+
+#[derive(Component)]
+struct BaseLayer;
+
+#[derive(Component)]
+struct IntenseLayer;
+
+pub struct SoundtrackPlugin;
+
+impl Plugin for SoundtrackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_music)
+            .add_systems(OnEnter(GameState::GameOver), fade_out_music)
+            .add_systems(OnExit(GameState::GameOver), resume_music)
+            .add_systems(Update, (blend_music, sync_music_tempo));
+    }
+}
+
+fn spawn_music(mut commands: Commands, asset_server: Res<AssetServer>) {
+    info!("Spawning music");
+
+    let music = DynamicMusicLayer {
+        base: asset_server.load("music/base.ogg"),
+        intense: asset_server.load("music/intense.ogg"),
+        blend: 0.,
+        tempo: 1.,
+        volume: 1.,
+        playing: true,
+    };
+
+    // both layers play in lockstep from the start, the
+    // intense one is just silent until the score diverges
+    commands.spawn((
+        AudioBundle {
+            source: music.base.clone(),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Loop,
+                volume: Volume::new(1.),
+                ..default()
+            },
+        },
+        BaseLayer,
+    ));
+
+    commands.spawn((
+        AudioBundle {
+            source: music.intense.clone(),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Loop,
+                volume: Volume::new(0.),
+                ..default()
+            },
+        },
+        IntenseLayer,
+    ));
+
+    commands.insert_resource(music);
+}
+
+fn fade_out_music(mut music: ResMut<DynamicMusicLayer>) {
+    music.playing = false;
+}
+
+// on a rematch, the volume comes straight back rather than fading in
+fn resume_music(mut music: ResMut<DynamicMusicLayer>) {
+    music.playing = true;
+    music.volume = 1.;
+}
+
+fn blend_music(
+    mut music: ResMut<DynamicMusicLayer>,
+    base: Query<&AudioSink, With<BaseLayer>>,
+    intense: Query<&AudioSink, (With<IntenseLayer>, Without<BaseLayer>)>,
+    score: Res<Score>,
+    time: Res<Time>,
+) {
+    let score_diff = (score.player as i32 - score.ai as i32).abs() as f32;
+    let target = (score_diff / MAX_INTENSITY_SCORE_DIFF).min(1.);
+
+    // move towards the target gradually so the layers crossfade
+    let max_step = BLEND_SPEED * time.delta_seconds();
+    music.blend += (target - music.blend).clamp(-max_step, max_step);
+
+    if !music.playing {
+        music.volume = (music.volume - time.delta_seconds() / FADE_OUT_SECS).max(0.);
+    }
+
+    // the sinks only exist once the sources have loaded, and
+    // never do if the files are missing, so the game plays on silently
+    if let Ok(sink) = base.get_single() {
+        sink.set_volume((1. - music.blend) * music.volume);
+    }
+    if let Ok(sink) = intense.get_single() {
+        sink.set_volume(music.blend * music.volume);
+    }
+}
+
+// Speeds the music up with the fastest ball in play
+fn sync_music_tempo(
+    mut music: ResMut<DynamicMusicLayer>,
+    sinks: Query<&AudioSink, Or<(With<BaseLayer>, With<IntenseLayer>)>>,
+    balls: Query<&Velocity, With<Ball>>,
+    simulation_speed: Res<SimulationSpeed>,
+    time: Res<Time<Real>>,
+) {
+    let ball_speed = balls
+        .iter()
+        .map(|velocity| velocity.0.length())
+        .fold(MIN_BALL_SPEED, f32::max);
+    let boost = (ball_speed - MIN_BALL_SPEED) / (MAX_BALL_SPEED - MIN_BALL_SPEED);
+    // slow motion pulls the tempo under 1, but speeding the game up
+    // is left to the ball
+    let slow_motion = simulation_speed.0.min(1.).powf(SLOW_MOTION_TEMPO_EXPONENT);
+    let target = (1. + boost.min(1.) * MAX_TEMPO_BOOST) * slow_motion;
+
+    // on real time, so the music keeps easing during slow motion
+    let step = (TEMPO_LERP_SPEED * time.delta_seconds()).min(1.);
+    music.tempo += (target - music.tempo) * step;
+
+    for sink in &sinks {
+        sink.set_speed(music.tempo);
+    }
+}
@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+
+use crate::powerups::PowerupCollected;
+use crate::{PaddleHit, Scored, Scorer};
+
+const FADE_IN_SECS: f32 = 0.2;
+const HOLD_SECS: f32 = 1.;
+const FADE_OUT_SECS: f32 = 0.5;
+// enough for a callout to fade in while the last two fade out
+const POOL_SIZE: usize = 3;
+// same as the event log, a rally this long deserves a mention
+const LONG_RALLY_HITS: u32 = 10;
+
+// Phrases to pick from for each kind of callout
+#[derive(Resource)]
+struct CommentaryPool {
+    player_scored: Vec<&'static str>,
+    ai_scored: Vec<&'static str>,
+    rally_broke: Vec<&'static str>,
+    powerup_collected: Vec<&'static str>,
+}
+
+impl Default for CommentaryPool {
+    fn default() -> Self {
+        CommentaryPool {
+            player_scored: vec![
+                "WHAT A SHOT!",
+                "UNSTOPPABLE!",
+                "RIGHT PAST THEM!",
+                "THE CROWD GOES WILD!",
+                "TEXTBOOK!",
+            ],
+            ai_scored: vec![
+                "POINT FOR AI!",
+                "THE MACHINE STRIKES!",
+                "OUTPLAYED!",
+                "COLD AND CALCULATED!",
+                "NO ANSWER TO THAT!",
+            ],
+            rally_broke: vec![
+                "WHAT A RALLY!",
+                "SPECTACULAR SAVE!",
+                "EPIC EXCHANGE!",
+                "THEY FINALLY CRACKED!",
+                "A RALLY FOR THE AGES!",
+            ],
+            powerup_collected: vec![
+                "POWERED UP!",
+                "OH, THAT'S HANDY!",
+                "A GIFT FROM ABOVE!",
+                "GAME CHANGER!",
+                "SNATCHED IT!",
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum FadeState {
+    Idle,
+    FadingIn,
+    Holding(f32),
+    FadingOut,
+}
+
+#[derive(Component)]
+struct FadeText {
+    alpha: f32,
+    state: FadeState,
+}
+
+#[derive(Resource, Default)]
+struct RallyHits(u32);
+
+pub struct NarratorPlugin;
+
+impl Plugin for NarratorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CommentaryPool>()
+            .init_resource::<RallyHits>()
+            .add_systems(Startup, spawn_callout_pool)
+            .add_systems(Update, (narrate, fade_callouts.after(narrate)));
+    }
+}
+
+// Callouts reuse these rather than spawning text for every event
+fn spawn_callout_pool(mut commands: Commands) {
+    for _ in 0..POOL_SIZE {
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(35.),
+                    width: Val::Percent(100.),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|parent| {
+                parent.spawn((
+                    TextBundle {
+                        visibility: Visibility::Hidden,
+                        ..TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 70.,
+                                color: Color::srgba(1., 0.9, 0.3, 0.),
+                                ..default()
+                            },
+                        )
+                    },
+                    FadeText {
+                        alpha: 0.,
+                        state: FadeState::Idle,
+                    },
+                ));
+            });
+    }
+}
+
+fn narrate(
+    pool: Res<CommentaryPool>,
+    mut rally_hits: ResMut<RallyHits>,
+    mut paddle_hits: EventReader<PaddleHit>,
+    mut scored_events: EventReader<Scored>,
+    mut collected: EventReader<PowerupCollected>,
+    mut callouts: Query<(&mut Text, &mut FadeText, &mut Visibility)>,
+) {
+    rally_hits.0 += paddle_hits.read().count() as u32;
+
+    let powerups_collected = collected.read().count();
+
+    // a point is bigger news than a pickup in the same frame
+    let phrases = if let Some(event) = scored_events.read().last() {
+        let rally_hits = std::mem::take(&mut rally_hits.0);
+        if rally_hits >= LONG_RALLY_HITS {
+            &pool.rally_broke
+        } else {
+            match event.0 {
+                Scorer::Player => &pool.player_scored,
+                Scorer::Ai => &pool.ai_scored,
+            }
+        }
+    } else if powerups_collected > 0 {
+        &pool.powerup_collected
+    } else {
+        return;
+    };
+    let Some(phrase) = phrases.choose(&mut rand::thread_rng()) else {
+        return;
+    };
+
+    // only the newest callout stays on screen
+    for (_, mut fade, _) in &mut callouts {
+        if fade.state != FadeState::Idle {
+            fade.state = FadeState::FadingOut;
+        }
+    }
+    let idle = callouts
+        .iter_mut()
+        .find(|(_, fade, _)| fade.state == FadeState::Idle);
+    if let Some((mut text, mut fade, mut visibility)) = idle {
+        text.sections[0].value = phrase.to_string();
+        fade.state = FadeState::FadingIn;
+        *visibility = Visibility::Inherited;
+    }
+}
+
+fn fade_callouts(
+    mut callouts: Query<(&mut Text, &mut FadeText, &mut Visibility)>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_seconds();
+
+    for (mut text, mut fade, mut visibility) in &mut callouts {
+        fade.state = match fade.state {
+            FadeState::Idle => continue,
+            FadeState::FadingIn => {
+                fade.alpha = (fade.alpha + delta / FADE_IN_SECS).min(1.);
+                if fade.alpha == 1. {
+                    FadeState::Holding(HOLD_SECS)
+                } else {
+                    FadeState::FadingIn
+                }
+            }
+            FadeState::Holding(secs) if secs > delta => FadeState::Holding(secs - delta),
+            FadeState::Holding(_) => FadeState::FadingOut,
+            FadeState::FadingOut => {
+                fade.alpha = (fade.alpha - delta / FADE_OUT_SECS).max(0.);
+                if fade.alpha == 0. {
+                    *visibility = Visibility::Hidden;
+                    FadeState::Idle
+                } else {
+                    FadeState::FadingOut
+                }
+            }
+        };
+
+        let alpha = fade.alpha;
+        text.sections[0].style.color.set_alpha(alpha);
+    }
+}
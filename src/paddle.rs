@@ -0,0 +1,336 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+
+use crate::ball::move_ball;
+use crate::game_state::GameState;
+use crate::gutter::Gutter;
+use crate::key_bindings::KeyBindings;
+use crate::paddle_customization::PaddleShape;
+use crate::post_score_freeze::post_score_freeze_over;
+use crate::profile::PlayerProfile;
+use crate::rotating_playfield::PlayfieldRotation;
+use crate::slippery_floor::PaddlePhysics;
+use crate::team::{spawn_teammates, Team, TeamMode};
+use crate::{Position, Shape, Velocity};
+
+pub const PADDLE_WIDTH: f32 = 10.;
+pub const PADDLE_HEIGHT: f32 = 50.;
+// distance from the side of the field to a paddle's centre
+pub const PADDLE_PADDING: f32 = 50.;
+// px per physics step, like every `Velocity`, so 300px/s at 60Hz
+pub const PADDLE_SPEED: f32 = 5.;
+// how fast the mesh eases to a new height, closing 95% of the gap
+// in 0.3s, as ln(20) / 0.3
+const HEIGHT_EASE_SPEED: f32 = 10.;
+// close enough to snap onto the new height and stop
+const HEIGHT_EPSILON: f32 = 0.001;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Paddle;
+
+// What a paddle's height is made of. Each feature owns its own part
+// and `apply_paddle_height` works the `Shape` out from all of them,
+// so a powerup stacks with the catch up bonus rather than one
+// overwriting the other.
+#[derive(Component)]
+pub struct PaddleHeightModifiers {
+    // set by hand in the paddle resize mode
+    pub base: f32,
+    pub handicap: f32,
+    pub powerup: f32,
+}
+
+impl Default for PaddleHeightModifiers {
+    fn default() -> Self {
+        PaddleHeightModifiers {
+            base: PADDLE_HEIGHT,
+            handicap: 1.,
+            powerup: 1.,
+        }
+    }
+}
+
+impl PaddleHeightModifiers {
+    pub fn height(&self) -> f32 {
+        self.base * self.handicap * self.powerup
+    }
+}
+
+#[derive(Bundle)]
+pub struct PaddleBundle {
+    pub paddle: Paddle,
+    pub position: Position,
+    pub shape: Shape,
+    pub height: PaddleHeightModifiers,
+    pub velocity: Velocity,
+}
+
+impl PaddleBundle {
+    pub fn new(x: f32, y: f32) -> Self {
+        PaddleBundle {
+            paddle: Paddle,
+            position: Position(Vec2::new(x, y)),
+            velocity: Velocity(Vec2::new(0., 0.)),
+            shape: Shape(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+            height: PaddleHeightModifiers::default(),
+        }
+    }
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Player;
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Ai;
+
+#[derive(Resource)]
+pub struct PaddleAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<ColorMaterial>,
+    // the player's paddle has its own colour from their profile
+    // and its own shape from the paddle customization screen
+    pub player_material: Handle<ColorMaterial>,
+    pub player_mesh: Handle<Mesh>,
+}
+
+impl PaddleAssets {
+    pub fn bundle(&self) -> MaterialMesh2dBundle<ColorMaterial> {
+        MaterialMesh2dBundle {
+            mesh: self.mesh.clone().into(),
+            material: self.material.clone(),
+            ..default()
+        }
+    }
+
+    pub fn player_bundle(&self) -> MaterialMesh2dBundle<ColorMaterial> {
+        MaterialMesh2dBundle {
+            mesh: self.player_mesh.clone().into(),
+            material: self.player_material.clone(),
+            ..self.bundle()
+        }
+    }
+}
+
+pub struct PaddlePlugin;
+
+impl Plugin for PaddlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_paddles)
+            .add_systems(
+                FixedUpdate,
+                (
+                    // friction first, so a held key always moves at full speed
+                    apply_paddle_friction.after(move_ball),
+                    handle_player_input
+                        .after(apply_paddle_friction)
+                        .run_if(post_score_freeze_over),
+                    move_paddles
+                        .after(handle_player_input)
+                        .run_if(post_score_freeze_over),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, apply_paddle_height);
+    }
+}
+
+// The profile, paddle shape and team mode are each left out along
+// with the feature owning them, which plays as its default
+fn spawn_paddles(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    window: Query<&Window>,
+    profile: Option<Res<PlayerProfile>>,
+    paddle_shape: Option<Res<PaddleShape>>,
+    team_mode: Option<Res<TeamMode>>,
+) {
+    println!("Spawning paddle");
+    // get the window
+    if let Ok(window) = window.get_single() {
+        let window_width = window.resolution.width();
+        let right_paddle_x = window_width / 2. - PADDLE_PADDING;
+        let left_paddle_x = -window_width / 2. + PADDLE_PADDING;
+
+        // make the meshes and materials
+
+        let shape = Mesh::from(Rectangle::new(PADDLE_WIDTH, PADDLE_HEIGHT));
+        let material = ColorMaterial::from_color(Color::srgb_u8(200, 100, 50));
+        let player_color = profile.map_or_else(
+            || PlayerProfile::default().paddle_color,
+            |profile| profile.paddle_color,
+        );
+        let player_shape = paddle_shape.as_deref().copied().unwrap_or_default();
+        let team_mode = team_mode.is_some_and(|mode| mode.0);
+
+        // add the meshes and materials to the asset manager
+        let paddle_assets = PaddleAssets {
+            mesh: meshes.add(shape),
+            material: materials.add(material),
+            player_material: materials.add(ColorMaterial::from_color(player_color)),
+            // the AI always keeps the plain rectangle
+            player_mesh: meshes.add(player_shape.mesh()),
+        };
+        // in team mode each side's first paddle plays the top half
+        let y = if team_mode {
+            window.resolution.height() / 4.
+        } else {
+            0.
+        };
+        let player = commands
+            .spawn((
+                Player,
+                PaddleBundle::new(left_paddle_x, y),
+                paddle_assets.player_bundle(),
+            ))
+            .id();
+        let ai = commands
+            .spawn((
+                Ai,
+                PaddleBundle::new(right_paddle_x, y),
+                paddle_assets.bundle(),
+            ))
+            .id();
+
+        if team_mode {
+            commands.entity(player).insert(Team::A);
+            commands.entity(ai).insert(Team::B);
+            spawn_teammates(
+                &mut commands,
+                &paddle_assets,
+                left_paddle_x,
+                right_paddle_x,
+                -y,
+            );
+        }
+        commands.insert_resource(paddle_assets);
+    }
+}
+
+pub fn handle_player_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    bindings: Option<Res<KeyBindings>>,
+    rotation: Option<Res<PlayfieldRotation>>,
+    mut player_paddle: Query<&mut Velocity, With<Player>>,
+    mut ai_paddle: Query<&mut Velocity, (With<Ai>, Without<Player>)>,
+) {
+    // with no key held a paddle is left to `apply_paddle_friction`,
+    // and the keys follow the screen when the playfield is turned
+    let speed = PADDLE_SPEED * rotation.map_or(1., |rotation| rotation.input_sign());
+    let bindings = bindings.as_deref().cloned().unwrap_or_default();
+
+    if let Ok(mut velocity) = player_paddle.get_single_mut() {
+        if keyboard_input.pressed(bindings.player_up) {
+            velocity.0.y = speed;
+        } else if keyboard_input.pressed(bindings.player_down) {
+            velocity.0.y = -speed;
+        }
+    }
+
+    if let Ok(mut velocity) = ai_paddle.get_single_mut() {
+        if keyboard_input.pressed(bindings.ai_up) {
+            velocity.0.y = speed;
+        } else if keyboard_input.pressed(bindings.ai_down) {
+            velocity.0.y = -speed;
+        }
+    }
+}
+
+pub fn apply_paddle_friction(
+    physics: Option<Res<PaddlePhysics>>,
+    mut paddles: Query<&mut Velocity, With<Paddle>>,
+) {
+    let friction = physics.map_or(PaddlePhysics::default().friction, |physics| {
+        physics.friction
+    });
+    for mut velocity in &mut paddles {
+        velocity.0.y = if friction >= 1. {
+            0.
+        } else {
+            velocity.0.y * friction
+        };
+    }
+}
+
+pub fn move_paddles(
+    mut paddles: Query<(&mut Position, &Velocity, &Shape), With<Paddle>>,
+    gutters: Query<(&Position, &Shape), (With<Gutter>, Without<Paddle>)>,
+    window: Query<&Window>,
+) {
+    if let Ok(window) = window.get_single() {
+        let window_height = window.resolution.height();
+
+        // gutters can move or break, so the play field is bounded by
+        // wherever they are right now, or the window edge without them
+        let mut top = window_height / 2.;
+        let mut bottom = -window_height / 2.;
+        for (position, shape) in &gutters {
+            if position.0.y > 0. {
+                top = top.min(position.0.y - shape.0.y / 2.);
+            } else {
+                bottom = bottom.max(position.0.y + shape.0.y / 2.);
+            }
+        }
+
+        // paddles can change size, so clamp with their current shape
+        for (mut position, velocity, shape) in &mut paddles {
+            let half_height = shape.0.y / 2.;
+            position.0.y += velocity.0.y;
+            position.0.y = position.0.y.max(bottom + half_height);
+            position.0.y = position.0.y.min(top - half_height);
+        }
+    }
+}
+
+// The `Shape` changes at once, so the ball can't slip through a paddle
+// that looks bigger than it is. Only the mesh eases to the new height.
+fn apply_paddle_height(
+    mut paddles: Query<(&PaddleHeightModifiers, &mut Shape, &mut Transform)>,
+    time: Res<Time>,
+) {
+    for (modifiers, mut shape, mut transform) in &mut paddles {
+        let height = modifiers.height();
+        if shape.0.y != height {
+            shape.0.y = height;
+        }
+
+        // the mesh is built at the normal height, so scale it to the shape
+        let target = height / PADDLE_HEIGHT;
+        if transform.scale.y == target {
+            continue;
+        }
+        let step = 1. - (-HEIGHT_EASE_SPEED * time.delta_seconds()).exp();
+        transform.scale.y = transform.scale.y.lerp(target, step);
+        if (transform.scale.y - target).abs() < HEIGHT_EPSILON {
+            transform.scale.y = target;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_mode::{headless_app, run_frames};
+
+    #[test]
+    fn paddle_height_modifiers_stack() {
+        let mut app = headless_app(vec![]);
+        // the paddles are spawned on the first update
+        run_frames(&mut app, 1);
+        let mut paddles = app
+            .world_mut()
+            .query_filtered::<&mut PaddleHeightModifiers, With<Player>>();
+        let mut height = paddles.single_mut(app.world_mut());
+        // resized by hand, behind on points and holding a powerup
+        height.base = 40.;
+        height.handicap = 1.3;
+        height.powerup = 1.5;
+        run_frames(&mut app, 1);
+
+        let mut paddles = app.world_mut().query_filtered::<&Shape, With<Player>>();
+        let shape = paddles.single(app.world());
+        assert!((shape.0.y - 78.).abs() < 1e-4, "{}", shape.0.y);
+    }
+}
@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+use serde::{Deserialize, Serialize};
+
+use crate::localization::{t, Locale};
+use crate::{settings, PaddleAssets, PADDLE_HEIGHT, PADDLE_WIDTH};
+
+const PREVIEW_SPACING: f32 = 150.;
+const PREVIEW_SCALE: f32 = 1.5;
+const SELECTED_PREVIEW_SCALE: f32 = 2.5;
+// in front of everything on the field
+const PREVIEW_Z: f32 = 10.;
+
+// The look of the player's paddle. Only the mesh changes, collisions
+// still use the rectangle of the paddle's `Shape` whatever it looks
+// like, so the rounded ends of an oval or hexagon still bounce the
+// ball as if they were square corners.
+#[derive(Resource, Serialize, Deserialize, Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PaddleShape {
+    #[default]
+    Rectangle,
+    Oval,
+    Hexagon,
+}
+
+impl PaddleShape {
+    const ALL: [PaddleShape; 3] = [
+        PaddleShape::Rectangle,
+        PaddleShape::Oval,
+        PaddleShape::Hexagon,
+    ];
+
+    fn index(self) -> usize {
+        match self {
+            PaddleShape::Rectangle => 0,
+            PaddleShape::Oval => 1,
+            PaddleShape::Hexagon => 2,
+        }
+    }
+
+    fn label_key(self) -> &'static str {
+        match self {
+            PaddleShape::Rectangle => "shape_rectangle",
+            PaddleShape::Oval => "shape_oval",
+            PaddleShape::Hexagon => "shape_hexagon",
+        }
+    }
+
+    // always fills the same box as the rectangle
+    pub fn mesh(self) -> Mesh {
+        match self {
+            PaddleShape::Rectangle => Rectangle::new(PADDLE_WIDTH, PADDLE_HEIGHT).into(),
+            PaddleShape::Oval => Ellipse::new(PADDLE_WIDTH / 2., PADDLE_HEIGHT / 2.).into(),
+            // a unit hexagon is sqrt(3) across its flat sides
+            PaddleShape::Hexagon => Mesh::from(RegularPolygon::new(1., 6)).scaled_by(Vec3::new(
+                PADDLE_WIDTH / 3f32.sqrt(),
+                PADDLE_HEIGHT / 2.,
+                1.,
+            )),
+        }
+    }
+}
+
+// The shape being looked at, which only becomes the
+// `PaddleShape` once it's confirmed
+#[derive(Resource, Default)]
+struct PaddleCustomization {
+    open: bool,
+    selected: PaddleShape,
+}
+
+#[derive(Component)]
+struct PaddlePreview(PaddleShape);
+
+#[derive(Component)]
+struct PaddleCustomizationText;
+
+pub struct PaddleCustomizationPlugin;
+
+impl Plugin for PaddleCustomizationPlugin {
+    fn build(&self, app: &mut App) {
+        // loaded up front so `spawn_paddles` can read it at startup
+        app.insert_resource(settings::load().paddle_shape)
+            .init_resource::<PaddleCustomization>()
+            .add_systems(Startup, spawn_paddle_customization)
+            .add_systems(
+                Update,
+                (
+                    toggle_paddle_customization,
+                    choose_paddle_shape,
+                    update_paddle_customization,
+                )
+                    .chain(),
+            );
+    }
+}
+
+// the previews get the player's material once they're first shown
+fn spawn_paddle_customization(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+    for shape in PaddleShape::ALL {
+        let x = (shape.index() as f32 - 1.) * PREVIEW_SPACING;
+        commands.spawn((
+            PaddlePreview(shape),
+            MaterialMesh2dBundle::<ColorMaterial> {
+                mesh: meshes.add(shape.mesh()).into(),
+                transform: Transform::from_xyz(x, 0., PREVIEW_Z),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+        ));
+    }
+
+    commands.spawn((
+        TextBundle {
+            visibility: Visibility::Hidden,
+            ..TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 24.,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            )
+            .with_text_justify(JustifyText::Center)
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(15.),
+                left: Val::Percent(30.),
+                padding: UiRect::all(Val::Px(10.)),
+                ..default()
+            })
+            .with_background_color(Color::srgba(0., 0., 0., 0.8))
+        },
+        PaddleCustomizationText,
+    ));
+}
+
+// There is no settings menu yet, so U opens the screen
+fn toggle_paddle_customization(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    shape: Res<PaddleShape>,
+    mut customization: ResMut<PaddleCustomization>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyU) {
+        customization.open = !customization.open;
+        // start from what's in use, not an unconfirmed pick
+        customization.selected = *shape;
+    }
+}
+
+fn choose_paddle_shape(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut customization: ResMut<PaddleCustomization>,
+    mut shape: ResMut<PaddleShape>,
+) {
+    if !customization.open {
+        return;
+    }
+
+    let index = customization.selected.index();
+    let count = PaddleShape::ALL.len();
+    if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        customization.selected = PaddleShape::ALL[(index + count - 1) % count];
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        customization.selected = PaddleShape::ALL[(index + 1) % count];
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        *shape = customization.selected;
+        let saved = *shape;
+        settings::update(|settings| settings.paddle_shape = saved);
+        info!("Paddle shape: {:?}", saved);
+        customization.open = false;
+    }
+}
+
+fn update_paddle_customization(
+    customization: Res<PaddleCustomization>,
+    shape: Res<PaddleShape>,
+    locale: Res<Locale>,
+    paddle_assets: Option<Res<PaddleAssets>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut previews: Query<(
+        &PaddlePreview,
+        &mut Transform,
+        &mut Visibility,
+        &mut Handle<ColorMaterial>,
+    )>,
+    mut text: Query<
+        (&mut Text, &mut Visibility),
+        (With<PaddleCustomizationText>, Without<PaddlePreview>),
+    >,
+) {
+    if shape.is_changed() {
+        // every paddle using the player's mesh picks up the new shape
+        if let Some(assets) = &paddle_assets {
+            meshes.insert(&assets.player_mesh, shape.mesh());
+        }
+    }
+
+    if !customization.is_changed() && !locale.is_changed() {
+        return;
+    }
+
+    let visibility = if customization.open {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+
+    for (preview, mut transform, mut preview_visibility, mut material) in &mut previews {
+        *preview_visibility = visibility;
+        transform.scale = Vec3::splat(if preview.0 == customization.selected {
+            SELECTED_PREVIEW_SCALE
+        } else {
+            PREVIEW_SCALE
+        });
+        if let Some(assets) = &paddle_assets {
+            *material = assets.player_material.clone();
+        }
+    }
+
+    if let Ok((mut text, mut text_visibility)) = text.get_single_mut() {
+        *text_visibility = visibility;
+        text.sections[0].value = format!(
+            "{}\n< {} >\n{}",
+            t("paddle_shape", &locale),
+            t(customization.selected.label_key(), &locale),
+            t("paddle_shape_help", &locale),
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::powerups::{PowerupCollected, PowerupKind};
+use crate::PaddleHeightModifiers;
+
+const BIGGER_PADDLE_SCALE: f32 = 1.5;
+const BIGGER_PADDLE_SECS: f32 = 8.;
+
+// On a paddle while the powerup lasts
+#[derive(Component)]
+struct BiggerPaddle(Timer);
+
+pub struct PaddleGrowPlugin;
+
+impl Plugin for PaddleGrowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (grow_paddles, shrink_paddles.after(grow_paddles)).run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+fn grow_paddles(
+    mut commands: Commands,
+    mut collected: EventReader<PowerupCollected>,
+    mut paddles: Query<(&mut PaddleHeightModifiers, Option<&mut BiggerPaddle>)>,
+) {
+    for event in collected.read() {
+        if event.kind != PowerupKind::BiggerPaddle {
+            continue;
+        }
+        let Ok((mut height, bigger)) = paddles.get_mut(event.paddle) else {
+            continue;
+        };
+
+        // another pickup while big just makes it last longer
+        if let Some(mut bigger) = bigger {
+            bigger.0.reset();
+            continue;
+        }
+
+        height.powerup = BIGGER_PADDLE_SCALE;
+        commands
+            .entity(event.paddle)
+            .insert(BiggerPaddle(Timer::from_seconds(
+                BIGGER_PADDLE_SECS,
+                TimerMode::Once,
+            )));
+    }
+}
+
+fn shrink_paddles(
+    mut commands: Commands,
+    mut paddles: Query<(Entity, &mut BiggerPaddle, &mut PaddleHeightModifiers)>,
+    time: Res<Time>,
+) {
+    for (entity, mut bigger, mut height) in &mut paddles {
+        if bigger.0.tick(time.delta()).finished() {
+            height.powerup = 1.;
+            commands.entity(entity).remove::<BiggerPaddle>();
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::{PaddleHeightModifiers, Player, PADDLE_HEIGHT};
+
+const RESIZE_PER_LINE: f32 = 5.;
+// touchpads scroll in pixels, roughly this many make up a line
+const PIXELS_PER_LINE: f32 = 20.;
+const MIN_PADDLE_HEIGHT: f32 = 20.;
+const MAX_PADDLE_HEIGHT: f32 = 120.;
+
+// Game mode where the player sizes their own paddle with the
+// scroll wheel. The AI paddle always keeps its size.
+#[derive(Resource, Default)]
+struct PaddleResizeEnabled(bool);
+
+pub struct PaddleResizePlugin;
+
+impl Plugin for PaddleResizePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PaddleResizeEnabled>().add_systems(
+            Update,
+            (
+                toggle_paddle_resize,
+                resize_player_paddle
+                    .after(toggle_paddle_resize)
+                    .run_if(in_state(GameState::Playing)),
+            ),
+        );
+    }
+}
+
+fn toggle_paddle_resize(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut enabled: ResMut<PaddleResizeEnabled>,
+    mut paddle: Query<&mut PaddleHeightModifiers, With<Player>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F1) {
+        enabled.0 = !enabled.0;
+        info!("Paddle resizing: {}", enabled.0);
+
+        // leaving the mode puts the paddle back to normal
+        if !enabled.0 {
+            if let Ok(mut height) = paddle.get_single_mut() {
+                height.base = PADDLE_HEIGHT;
+            }
+        }
+    }
+}
+
+fn resize_player_paddle(
+    enabled: Res<PaddleResizeEnabled>,
+    mut scroll_events: EventReader<MouseWheel>,
+    mut paddle: Query<&mut PaddleHeightModifiers, With<Player>>,
+) {
+    if !enabled.0 {
+        scroll_events.clear();
+        return;
+    }
+
+    let lines: f32 = scroll_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / PIXELS_PER_LINE,
+        })
+        .sum();
+    if lines == 0. {
+        return;
+    }
+
+    // only the paddle's own height, bonuses still go on top of it
+    if let Ok(mut height) = paddle.get_single_mut() {
+        height.base =
+            (height.base + lines * RESIZE_PER_LINE).clamp(MIN_PADDLE_HEIGHT, MAX_PADDLE_HEIGHT);
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::game_state::{AddGameMode, GameState};
+use crate::{break_gutters, detect_scoring, move_ball, Ball, Gutter, Position, Shape, Velocity};
+
+// how much a warp can change the ball's vertical speed, either way
+const WARP_Y_JITTER: f32 = 0.2;
+
+// Game variant where the left and right edges wrap around and
+// points are only scored through the open top and bottom
+#[derive(Resource, Default)]
+struct PortalMode(bool);
+
+pub struct PortalPlugin;
+
+impl Plugin for PortalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PortalMode>()
+            .add_game_mode(KeyCode::KeyP, "mode_portal", toggle_portal_mode)
+            .add_systems(
+                FixedUpdate,
+                (
+                    // gutters come back every point, so keep them gone
+                    open_gutters.after(break_gutters),
+                    warp_balls.after(move_ball).before(detect_scoring),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn toggle_portal_mode(mut portal: ResMut<PortalMode>) {
+    portal.0 = !portal.0;
+    info!("Portal mode: {}", portal.0);
+}
+
+fn open_gutters(
+    mut commands: Commands,
+    portal: Res<PortalMode>,
+    gutters: Query<Entity, With<Gutter>>,
+) {
+    if !portal.0 {
+        return;
+    }
+    for entity in &gutters {
+        commands.entity(entity).despawn();
+    }
+}
+
+// Runs before `detect_scoring` so a ball leaving through the side
+// comes back in on the other one rather than scoring
+fn warp_balls(
+    portal: Res<PortalMode>,
+    mut balls: Query<(&mut Position, &mut Velocity, &Shape), With<Ball>>,
+    window: Query<&Window>,
+) {
+    if !portal.0 {
+        return;
+    }
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    let half_width = window.resolution.width() / 2.;
+    let mut rng = rand::thread_rng();
+
+    for (mut position, mut velocity, shape) in &mut balls {
+        if position.0.x.abs() <= half_width {
+            continue;
+        }
+
+        // just inside the opposite edge, still heading the same way
+        let radius = shape.0.x;
+        position.0.x = -position.0.x.signum() * (half_width - radius);
+        velocity.0.y *= 1. + rng.gen_range(-WARP_Y_JITTER..WARP_Y_JITTER);
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::{detect_scoring, Scored};
+
+const FREEZE_SECS: f32 = 2.;
+// how long "GO!" stays up once play resumes
+const GO_SECS: f32 = 0.5;
+
+// Holds the ball and paddles still for a moment after each
+// point, so nobody is caught mid motion by the next serve
+#[derive(Resource)]
+pub struct PostScoreFreeze {
+    pub active: bool,
+    timer: Timer,
+}
+
+impl Default for PostScoreFreeze {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(FREEZE_SECS + GO_SECS, TimerMode::Once);
+        // nothing to count down until someone scores
+        timer.tick(timer.duration());
+        PostScoreFreeze {
+            active: false,
+            timer,
+        }
+    }
+}
+
+#[derive(Component)]
+struct FreezeCountdown;
+
+pub struct PostScoreFreezePlugin;
+
+impl Plugin for PostScoreFreezePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PostScoreFreeze>()
+            .add_systems(OnExit(InMatch), reset_resource::<PostScoreFreeze>)
+            .add_systems(Startup, spawn_freeze_countdown)
+            .add_systems(
+                FixedUpdate,
+                (
+                    start_freeze.after(detect_scoring),
+                    tick_freeze.after(start_freeze),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, update_freeze_countdown);
+    }
+}
+
+// Run condition for the systems that stand still during the freeze,
+// which never comes without this plugin
+pub fn post_score_freeze_over(freeze: Option<Res<PostScoreFreeze>>) -> bool {
+    freeze.is_none_or(|freeze| !freeze.active)
+}
+
+fn spawn_freeze_countdown(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(25.),
+                width: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 60.,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ),
+                FreezeCountdown,
+            ));
+        });
+}
+
+fn start_freeze(mut freeze: ResMut<PostScoreFreeze>, mut scored_events: EventReader<Scored>) {
+    if scored_events.read().last().is_some() {
+        freeze.active = true;
+        freeze.timer.reset();
+    }
+}
+
+fn tick_freeze(mut freeze: ResMut<PostScoreFreeze>, time: Res<Time>) {
+    // keeps going past the freeze to time the "GO!"
+    if freeze.timer.finished() {
+        return;
+    }
+    freeze.timer.tick(time.delta());
+
+    if freeze.active && freeze.timer.elapsed_secs() >= FREEZE_SECS {
+        freeze.active = false;
+    }
+}
+
+fn update_freeze_countdown(
+    freeze: Res<PostScoreFreeze>,
+    mut countdown: Query<&mut Text, With<FreezeCountdown>>,
+) {
+    if !freeze.is_changed() {
+        return;
+    }
+    let Ok(mut text) = countdown.get_single_mut() else {
+        return;
+    };
+
+    text.sections[0].value = if freeze.active {
+        let remaining = FREEZE_SECS - freeze.timer.elapsed_secs();
+        format!("{}…", remaining.ceil())
+    } else if !freeze.timer.finished() {
+        "GO!".to_string()
+    } else {
+        String::new()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_mode::{headless_app, run_frames};
+
+    #[test]
+    fn no_go_before_the_first_point() {
+        let mut app = headless_app(vec![]);
+        app.add_plugins(PostScoreFreezePlugin);
+        run_frames(&mut app, 5);
+
+        let mut countdown = app
+            .world_mut()
+            .query_filtered::<&Text, With<FreezeCountdown>>();
+        assert_eq!(countdown.single(app.world()).sections[0].value, "");
+    }
+}
@@ -0,0 +1,172 @@
+use bevy::math::bounding::{BoundingCircle, IntersectsVolume};
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+use rand::{random, Rng};
+
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::{Ball, BallFrozen, LastHitBy, Position, Shape};
+
+const POWERUP_RADIUS: f32 = 12.;
+const POWERUP_SPAWN_SECS: f32 = 10.;
+const MAX_POWERUPS: usize = 2;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PowerupKind {
+    RacketSpin,
+    BiggerPaddle,
+    Wormhole,
+    ReboundPaddle,
+    GravityWell,
+    DoubleWidth,
+}
+
+impl PowerupKind {
+    pub const ALL: [PowerupKind; 6] = [
+        PowerupKind::RacketSpin,
+        PowerupKind::BiggerPaddle,
+        PowerupKind::Wormhole,
+        PowerupKind::ReboundPaddle,
+        PowerupKind::GravityWell,
+        PowerupKind::DoubleWidth,
+    ];
+
+    fn color(self) -> Color {
+        match self {
+            PowerupKind::RacketSpin => Color::srgb_u8(240, 120, 200),
+            PowerupKind::BiggerPaddle => Color::srgb_u8(120, 220, 120),
+            PowerupKind::Wormhole => Color::srgb_u8(150, 80, 230),
+            PowerupKind::ReboundPaddle => Color::srgb_u8(220, 220, 220),
+            PowerupKind::GravityWell => Color::srgb_u8(60, 60, 140),
+            PowerupKind::DoubleWidth => Color::srgb_u8(240, 200, 60),
+        }
+    }
+}
+
+#[derive(Component)]
+struct Powerup(PowerupKind);
+
+// Sent when a ball runs over a powerup, crediting the
+// paddle that last hit that ball
+#[derive(Event)]
+pub struct PowerupCollected {
+    pub kind: PowerupKind,
+    pub paddle: Entity,
+}
+
+#[derive(Resource)]
+struct PowerupSpawnTimer(Timer);
+
+impl Default for PowerupSpawnTimer {
+    fn default() -> Self {
+        PowerupSpawnTimer(Timer::from_seconds(
+            POWERUP_SPAWN_SECS,
+            TimerMode::Repeating,
+        ))
+    }
+}
+
+#[derive(Resource)]
+struct PowerupAssets {
+    mesh: Handle<Mesh>,
+    materials: Vec<(PowerupKind, Handle<ColorMaterial>)>,
+}
+
+pub struct PowerupPlugin;
+
+impl Plugin for PowerupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PowerupCollected>()
+            .init_resource::<PowerupSpawnTimer>()
+            .add_systems(Startup, load_powerup_assets)
+            .add_systems(OnEnter(InMatch), reset_resource::<PowerupSpawnTimer>)
+            .add_systems(Update, spawn_powerups.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                FixedUpdate,
+                collect_powerups.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn load_powerup_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.insert_resource(PowerupAssets {
+        mesh: meshes.add(Circle::new(POWERUP_RADIUS)),
+        materials: PowerupKind::ALL
+            .iter()
+            .map(|kind| {
+                (
+                    *kind,
+                    materials.add(ColorMaterial::from_color(kind.color())),
+                )
+            })
+            .collect(),
+    });
+}
+
+fn spawn_powerups(
+    mut commands: Commands,
+    mut timer: ResMut<PowerupSpawnTimer>,
+    powerups: Query<(), With<Powerup>>,
+    powerup_assets: Res<PowerupAssets>,
+    ball_frozen: Res<BallFrozen>,
+    window: Query<&Window>,
+    time: Res<Time>,
+) {
+    if ball_frozen.0 || !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+    if powerups.iter().count() >= MAX_POWERUPS {
+        return;
+    }
+
+    if let Ok(window) = window.get_single() {
+        // keep powerups in the middle half of the field
+        let x = (random::<f32>() - 0.5) * window.resolution.width() / 2.;
+        let y = (random::<f32>() - 0.5) * window.resolution.height() / 2.;
+
+        let (kind, material) =
+            &powerup_assets.materials[rand::thread_rng().gen_range(0..PowerupKind::ALL.len())];
+
+        commands.spawn((
+            Powerup(*kind),
+            Position(Vec2::new(x, y)),
+            StateScoped(InMatch),
+            MaterialMesh2dBundle {
+                mesh: powerup_assets.mesh.clone().into(),
+                material: material.clone(),
+                transform: Transform::from_xyz(x, y, 0.),
+                ..default()
+            },
+        ));
+    }
+}
+
+fn collect_powerups(
+    mut commands: Commands,
+    balls: Query<(&Position, &Shape, &LastHitBy), With<Ball>>,
+    powerups: Query<(Entity, &Position, &Powerup)>,
+    mut collected: EventWriter<PowerupCollected>,
+) {
+    for (ball_position, ball_shape, last_hit_by) in &balls {
+        // nobody has earned a served ball's pickups yet
+        let Some(paddle) = last_hit_by.0 else {
+            continue;
+        };
+
+        let ball_circle = BoundingCircle::new(ball_position.0, ball_shape.0.x);
+        for (entity, position, powerup) in &powerups {
+            let powerup_circle = BoundingCircle::new(position.0, POWERUP_RADIUS);
+            if ball_circle.intersects(&powerup_circle) {
+                info!("Collected {:?}", powerup.0);
+                commands.entity(entity).despawn();
+                collected.send(PowerupCollected {
+                    kind: powerup.0,
+                    paddle,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::{ButtonState, InputSystem};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game_state::GameState;
+use crate::localization::{t, Locale};
+use crate::settings;
+use crate::PaddleAssets;
+
+const MAX_NAME_LEN: usize = 12;
+
+// the paddle colours a player can pick from,
+// the first one is the classic orange
+const PALETTE: [Color; 8] = [
+    Color::srgb(0.78, 0.39, 0.2),
+    Color::srgb(0.9, 0.2, 0.2),
+    Color::srgb(0.95, 0.85, 0.2),
+    Color::srgb(0.3, 0.8, 0.3),
+    Color::srgb(0.2, 0.8, 0.8),
+    Color::srgb(0.3, 0.4, 0.95),
+    Color::srgb(0.7, 0.3, 0.9),
+    Color::srgb(0.95, 0.95, 0.95),
+];
+
+#[derive(Resource, Serialize, Deserialize, Clone)]
+pub struct PlayerProfile {
+    pub name: String,
+    pub paddle_color: Color,
+}
+
+impl Default for PlayerProfile {
+    fn default() -> Self {
+        PlayerProfile {
+            name: "Player".to_string(),
+            paddle_color: PALETTE[0],
+        }
+    }
+}
+
+// Set while the player is typing in the profile editor. The keys are
+// taken back out of `ButtonInput` each frame, so typing a name doesn't
+// also set off every hotkey it spells, or start the match on Space.
+#[derive(Resource, Default)]
+pub struct EditingText(pub bool);
+
+#[derive(Component)]
+struct PlayerNameLabel;
+
+#[derive(Component)]
+struct ProfileEditorText;
+
+pub struct PlayerProfilePlugin;
+
+impl Plugin for PlayerProfilePlugin {
+    fn build(&self, app: &mut App) {
+        // loaded up front so `spawn_paddles` can read it at startup
+        app.insert_resource(settings::load().profile)
+            .init_resource::<EditingText>()
+            .add_systems(Startup, spawn_profile_ui)
+            .add_systems(
+                PreUpdate,
+                consume_typed_keys.after(InputSystem).run_if(editing_text),
+            )
+            .add_systems(
+                Update,
+                (
+                    // the key that opens the editor isn't typed into it
+                    edit_profile,
+                    open_profile_editor
+                        .after(edit_profile)
+                        .run_if(in_state(GameState::Menu)),
+                    show_profile_editor.after(open_profile_editor),
+                    apply_profile.after(edit_profile),
+                ),
+            );
+    }
+}
+
+fn spawn_profile_ui(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 16.,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(2.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        PlayerNameLabel,
+    ));
+
+    commands.spawn((
+        TextBundle {
+            visibility: Visibility::Hidden,
+            ..TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 24.,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(30.),
+                left: Val::Percent(30.),
+                padding: UiRect::all(Val::Px(10.)),
+                ..default()
+            })
+            .with_background_color(Color::srgba(0., 0., 0., 0.8))
+        },
+        ProfileEditorText,
+    ));
+}
+
+pub fn editing_text(editing: Res<EditingText>) -> bool {
+    editing.0
+}
+
+fn consume_typed_keys(mut keyboard_input: ResMut<ButtonInput<KeyCode>>) {
+    keyboard_input.reset_all();
+}
+
+// Only from the menu, so a match is never going on behind it
+fn open_profile_editor(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut editing: ResMut<EditingText>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F2) {
+        editing.0 = true;
+    }
+}
+
+fn show_profile_editor(
+    editing: Res<EditingText>,
+    mut editor_text: Query<&mut Visibility, With<ProfileEditorText>>,
+) {
+    if editing.is_changed() {
+        if let Ok(mut visibility) = editor_text.get_single_mut() {
+            *visibility = if editing.0 {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+}
+
+fn edit_profile(
+    mut editing: ResMut<EditingText>,
+    mut profile: ResMut<PlayerProfile>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+) {
+    if !editing.0 {
+        keyboard_events.clear();
+        return;
+    }
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        let color_index = PALETTE
+            .iter()
+            .position(|color| *color == profile.paddle_color)
+            .unwrap_or(0);
+
+        match &event.logical_key {
+            Key::Character(text) => {
+                for character in text.chars().filter(|c| c.is_alphanumeric() || *c == ' ') {
+                    if profile.name.chars().count() < MAX_NAME_LEN {
+                        profile.name.push(character);
+                    }
+                }
+            }
+            Key::Space if profile.name.chars().count() < MAX_NAME_LEN => {
+                profile.name.push(' ');
+            }
+            Key::Backspace => {
+                profile.name.pop();
+            }
+            Key::ArrowLeft => {
+                profile.paddle_color = PALETTE[(color_index + PALETTE.len() - 1) % PALETTE.len()];
+            }
+            Key::ArrowRight => {
+                profile.paddle_color = PALETTE[(color_index + 1) % PALETTE.len()];
+            }
+            Key::Enter => {
+                let saved = profile.clone();
+                settings::update(|settings| settings.profile = saved);
+                info!("Saved profile for {}", profile.name);
+                editing.0 = false;
+            }
+            Key::F2 => {
+                editing.0 = false;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn apply_profile(
+    profile: Res<PlayerProfile>,
+    locale: Res<Locale>,
+    paddle_assets: Option<Res<PaddleAssets>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut name_label: Query<&mut Text, With<PlayerNameLabel>>,
+    mut editor_text: Query<&mut Text, (With<ProfileEditorText>, Without<PlayerNameLabel>)>,
+) {
+    if !profile.is_changed() && !locale.is_changed() {
+        return;
+    }
+
+    if let Ok(mut text) = name_label.get_single_mut() {
+        text.sections[0].value = profile.name.clone();
+    }
+
+    if let Ok(mut text) = editor_text.get_single_mut() {
+        text.sections[0].value = format!(
+            "{}\n{}: {}_\n{}\n{}",
+            t("profile", &locale),
+            t("profile_name", &locale),
+            profile.name,
+            t("profile_colour", &locale),
+            t("profile_help", &locale),
+        );
+        text.sections[0].style.color = profile.paddle_color;
+    }
+
+    if let Some(material) =
+        paddle_assets.and_then(|assets| materials.get_mut(&assets.player_material))
+    {
+        material.color = profile.paddle_color;
+    }
+}
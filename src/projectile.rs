@@ -0,0 +1,219 @@
+use std::time::Duration;
+
+use bevy::math::bounding::{Aabb2d, IntersectsVolume};
+use bevy::prelude::*;
+
+use crate::energy::PlayerEnergy;
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::key_bindings::KeyBindings;
+use crate::{
+    handle_player_input, move_paddles, Ai, Ball, BallFrozen, Player, Position, Shape, Velocity,
+    MIN_BALL_SPEED,
+};
+
+const PROJECTILE_SIZE: Vec2 = Vec2::new(8., 4.);
+const PROJECTILE_SPEED_FACTOR: f32 = 3.;
+const STUN_SECS: f32 = 1.5;
+const COOLDOWN_SECS: f32 = 10.;
+const COOLDOWN_BAR_WIDTH: f32 = 60.;
+
+// Only the player can shoot, and only one shot can be in flight
+#[derive(Component)]
+struct Projectile;
+
+#[derive(Component)]
+struct Stunned(Timer);
+
+#[derive(Resource)]
+struct ProjectileCooldown(Timer);
+
+impl Default for ProjectileCooldown {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(COOLDOWN_SECS, TimerMode::Once);
+        // ready from the start
+        timer.tick(timer.duration());
+        ProjectileCooldown(timer)
+    }
+}
+
+#[derive(Component)]
+struct CooldownBarFill;
+
+pub struct ProjectilePlugin;
+
+impl Plugin for ProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProjectileCooldown>()
+            .add_systems(Startup, spawn_cooldown_bar)
+            .add_systems(OnEnter(InMatch), reset_resource::<ProjectileCooldown>)
+            .add_systems(
+                Update,
+                (
+                    fire_projectile.run_if(in_state(GameState::Playing)),
+                    update_cooldown_bar,
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    move_projectiles,
+                    hit_ai_paddle.after(move_projectiles),
+                    // overrides the AI's input for the step
+                    stun_ai_paddle
+                        .after(hit_ai_paddle)
+                        .after(handle_player_input)
+                        .before(move_paddles),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn fire_projectile(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut cooldown: ResMut<ProjectileCooldown>,
+    mut energy: ResMut<PlayerEnergy>,
+    ball_frozen: Res<BallFrozen>,
+    projectiles: Query<(), With<Projectile>>,
+    player: Query<&Position, With<Player>>,
+    balls: Query<&Velocity, With<Ball>>,
+    time: Res<Time>,
+) {
+    cooldown.0.tick(time.delta());
+
+    // the serve key also launches serves and moves the tutorial
+    // on, so shots are only fired while the ball is in play
+    if ball_frozen.0 || !keyboard_input.just_pressed(bindings.serve) {
+        return;
+    }
+    if !cooldown.0.finished() || !projectiles.is_empty() {
+        return;
+    }
+    let Ok(player_position) = player.get_single() else {
+        return;
+    };
+
+    let ball_speed = balls
+        .iter()
+        .map(|velocity| velocity.0.length())
+        .fold(MIN_BALL_SPEED, f32::max);
+
+    commands.spawn((
+        Projectile,
+        Position(player_position.0),
+        StateScoped(InMatch),
+        Velocity(Vec2::new(ball_speed * PROJECTILE_SPEED_FACTOR, 0.)),
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgb_u8(255, 80, 80),
+                custom_size: Some(PROJECTILE_SIZE),
+                ..default()
+            },
+            transform: Transform::from_translation(player_position.0.extend(0.)),
+            ..default()
+        },
+    ));
+    // a full energy bar is spent on a quicker reload
+    let reload = if energy.is_full() {
+        energy.0 = 0.;
+        COOLDOWN_SECS / 2.
+    } else {
+        COOLDOWN_SECS
+    };
+    cooldown.0.set_duration(Duration::from_secs_f32(reload));
+    cooldown.0.reset();
+    info!("Fired projectile");
+}
+
+fn move_projectiles(
+    mut commands: Commands,
+    mut projectiles: Query<(Entity, &mut Position, &Velocity), With<Projectile>>,
+    window: Query<&Window>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+
+    for (entity, mut position, velocity) in &mut projectiles {
+        position.0 += velocity.0;
+        if position.0.x > window.resolution.width() / 2. {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn hit_ai_paddle(
+    mut commands: Commands,
+    projectiles: Query<(Entity, &Position), With<Projectile>>,
+    ai_paddle: Query<(Entity, &Position, &Shape), With<Ai>>,
+) {
+    let Ok((paddle, paddle_position, paddle_shape)) = ai_paddle.get_single() else {
+        return;
+    };
+    let paddle_box = Aabb2d::new(paddle_position.0, paddle_shape.0 / 2.);
+
+    for (entity, position) in &projectiles {
+        if Aabb2d::new(position.0, PROJECTILE_SIZE / 2.).intersects(&paddle_box) {
+            info!("AI paddle stunned");
+            commands.entity(entity).despawn();
+            commands
+                .entity(paddle)
+                .insert(Stunned(Timer::from_seconds(STUN_SECS, TimerMode::Once)));
+        }
+    }
+}
+
+fn stun_ai_paddle(
+    mut commands: Commands,
+    mut stunned: Query<(Entity, &mut Stunned, &mut Velocity)>,
+    time: Res<Time>,
+) {
+    for (entity, mut stun, mut velocity) in &mut stunned {
+        velocity.0 = Vec2::ZERO;
+        if stun.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Stunned>();
+        }
+    }
+}
+
+fn spawn_cooldown_bar(mut commands: Commands) {
+    // just below the player's score
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(75.0),
+                left: Val::Px(10.0),
+                width: Val::Px(COOLDOWN_BAR_WIDTH),
+                height: Val::Px(4.0),
+                ..default()
+            },
+            background_color: Color::srgba(1., 1., 1., 0.2).into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.),
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    background_color: Color::srgb_u8(255, 80, 80).into(),
+                    ..default()
+                },
+                CooldownBarFill,
+            ));
+        });
+}
+
+fn update_cooldown_bar(
+    cooldown: Res<ProjectileCooldown>,
+    mut fill: Query<&mut Style, With<CooldownBarFill>>,
+) {
+    if let Ok(mut style) = fill.get_single_mut() {
+        style.width = Val::Percent(cooldown.0.fraction() * 100.);
+    }
+}
@@ -0,0 +1,51 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::powerups::{PowerupCollected, PowerupKind};
+
+const SPIN_SECS: f32 = 1.5;
+
+// Purely visual, collisions keep using the paddle's unrotated `Shape`
+#[derive(Component)]
+struct SpinPaddle {
+    remaining_rotations: f32,
+    // radians per second
+    speed: f32,
+}
+
+pub struct RacketSpinPlugin;
+
+impl Plugin for RacketSpinPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (start_racket_spin, spin_paddle));
+    }
+}
+
+fn start_racket_spin(mut commands: Commands, mut collected: EventReader<PowerupCollected>) {
+    for event in collected.read() {
+        if event.kind == PowerupKind::RacketSpin {
+            commands.entity(event.paddle).insert(SpinPaddle {
+                remaining_rotations: 1.,
+                speed: TAU / SPIN_SECS,
+            });
+        }
+    }
+}
+
+fn spin_paddle(
+    mut commands: Commands,
+    mut paddles: Query<(Entity, &mut SpinPaddle, &mut Transform)>,
+    time: Res<Time>,
+) {
+    for (entity, mut spin, mut transform) in &mut paddles {
+        let angle = spin.speed * time.delta_seconds();
+        transform.rotate_z(angle);
+        spin.remaining_rotations -= angle / TAU;
+
+        if spin.remaining_rotations <= 0. {
+            transform.rotation = Quat::IDENTITY;
+            commands.entity(entity).remove::<SpinPaddle>();
+        }
+    }
+}
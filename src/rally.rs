@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::settings;
+use crate::{handle_collisions, Ball, BallCollision, Paddle, Scored, Velocity, MAX_BALL_SPEED};
+
+// How much faster the ball gets with every paddle hit of a rally,
+// set in the settings file. A `percent_per_hit` of 0 turns it off.
+#[derive(Resource, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct RallySpeedUp {
+    pub percent_per_hit: f32,
+    pub max_speed: f32,
+}
+
+impl Default for RallySpeedUp {
+    fn default() -> Self {
+        RallySpeedUp {
+            percent_per_hit: 5.,
+            max_speed: MAX_BALL_SPEED,
+        }
+    }
+}
+
+// Paddle hits since the last point
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct RallyLength(pub u32);
+
+pub struct RallySpeedPlugin;
+
+impl Plugin for RallySpeedPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(settings::load().rally_speed_up)
+            .init_resource::<RallyLength>()
+            .add_systems(OnExit(InMatch), reset_resource::<RallyLength>)
+            .add_systems(
+                FixedUpdate,
+                (
+                    // once the bounce has set the ball's new direction
+                    speed_up_rally.after(handle_collisions),
+                    reset_rally,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn speed_up_rally(
+    mut collisions: EventReader<BallCollision>,
+    mut balls: Query<&mut Velocity, With<Ball>>,
+    paddles: Query<(), With<Paddle>>,
+    speed_up: Res<RallySpeedUp>,
+    mut rally: ResMut<RallyLength>,
+) {
+    for collision in collisions.read() {
+        if !paddles.contains(collision.entity) {
+            continue;
+        }
+        let Ok(mut velocity) = balls.get_mut(collision.ball) else {
+            continue;
+        };
+
+        rally.0 += 1;
+        // never slows a ball that's already past the cap some other way
+        let max_speed = speed_up.max_speed.max(velocity.0.length());
+        velocity.0 =
+            (velocity.0 * (1. + speed_up.percent_per_hit / 100.)).clamp_length_max(max_speed);
+    }
+}
+
+// the next serve comes in at serve speed anyway, only the count restarts
+fn reset_rally(mut goals: EventReader<Scored>, mut rally: ResMut<RallyLength>) {
+    if goals.read().last().is_some() {
+        rally.0 = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_mode::{headless_app, run_frames};
+
+    #[test]
+    fn rally_speeds_up_with_every_hit() {
+        let mut app = headless_app(vec![]);
+        app.add_plugins(RallySpeedPlugin)
+            .insert_resource(RallySpeedUp {
+                percent_per_hit: 10.,
+                max_speed: 6.,
+            });
+        // the serve goes straight at the AI paddle at 5 px per step,
+        // and comes back off the player's for a second hit
+        run_frames(&mut app, 400);
+
+        let mut balls = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let speed = balls.single(app.world()).0.length();
+        assert!(app.world().resource::<RallyLength>().0 >= 2);
+        assert!((speed - 6.).abs() < 1e-4, "capped at 6, not {speed}");
+    }
+}
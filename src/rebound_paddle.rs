@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+use rand::random;
+
+use crate::game_state::{GameState, InMatch};
+use crate::powerups::{PowerupCollected, PowerupKind};
+use crate::{handle_collisions, PaddleAssets, Position, Shape, PADDLE_HEIGHT, PADDLE_WIDTH};
+
+const GHOST_OFFSET: f32 = 100.;
+const GHOST_SECS: f32 = 5.;
+const GHOST_BOUNCES: u32 = 2;
+
+// An extra paddle the ball bounces off. Without a `Paddle`
+// marker it doesn't move and doesn't count as a hit.
+#[derive(Component)]
+struct GhostPaddle(Timer);
+
+// Bumped by `handle_collisions` for every bounce off its entity
+#[derive(Component, Default)]
+pub struct BounceCount(pub u32);
+
+#[derive(Resource)]
+struct GhostPaddleMaterial(Handle<ColorMaterial>);
+
+pub struct ReboundPaddlePlugin;
+
+impl Plugin for ReboundPaddlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_ghost_paddle_material)
+            .add_systems(
+                Update,
+                spawn_ghost_paddles.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                FixedUpdate,
+                despawn_ghost_paddles
+                    .after(handle_collisions)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn load_ghost_paddle_material(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.insert_resource(GhostPaddleMaterial(
+        materials.add(ColorMaterial::from_color(Color::srgba(1., 1., 1., 0.35))),
+    ));
+}
+
+fn spawn_ghost_paddles(
+    mut commands: Commands,
+    mut collected: EventReader<PowerupCollected>,
+    paddles: Query<&Position>,
+    paddle_assets: Option<Res<PaddleAssets>>,
+    material: Res<GhostPaddleMaterial>,
+    window: Query<&Window>,
+) {
+    let (Some(paddle_assets), Ok(window)) = (paddle_assets, window.get_single()) else {
+        return;
+    };
+
+    for event in collected.read() {
+        if event.kind != PowerupKind::ReboundPaddle {
+            continue;
+        }
+        let Ok(paddle_position) = paddles.get(event.paddle) else {
+            continue;
+        };
+
+        // in front of the paddle that earned it, towards the other side
+        let x = paddle_position.0.x - paddle_position.0.x.signum() * GHOST_OFFSET;
+        let y = (random::<f32>() - 0.5) * (window.resolution.height() - PADDLE_HEIGHT);
+
+        commands.spawn((
+            GhostPaddle(Timer::from_seconds(GHOST_SECS, TimerMode::Once)),
+            StateScoped(InMatch),
+            BounceCount::default(),
+            Position(Vec2::new(x, y)),
+            Shape(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+            MaterialMesh2dBundle {
+                material: material.0.clone(),
+                transform: Transform::from_xyz(x, y, 0.),
+                ..paddle_assets.bundle()
+            },
+        ));
+    }
+}
+
+fn despawn_ghost_paddles(
+    mut commands: Commands,
+    mut ghosts: Query<(Entity, &mut GhostPaddle, &BounceCount)>,
+    time: Res<Time>,
+) {
+    for (entity, mut ghost, bounces) in &mut ghosts {
+        let expired = ghost.0.tick(time.delta()).finished();
+        if expired || bounces.0 >= GHOST_BOUNCES {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::{
+    detect_scoring, move_ball, move_paddles, Ball, BallAssets, HitCount, Paddle, PaddleAssets,
+    Player, Position, Scored, BASE_TIMESTEP_HZ,
+};
+
+// rallies at least this long get a replay when they end
+const REPLAY_MIN_HITS: u32 = 15;
+// five seconds of physics steps
+const REPLAY_FRAMES: usize = 5 * BASE_TIMESTEP_HZ as usize;
+const REPLAY_SPEED: f32 = 0.3;
+
+struct WorldSnapshot {
+    balls: Vec<Vec2>,
+    // position, and whether it's the player's paddle
+    paddles: Vec<(Vec2, bool)>,
+}
+
+// The last `REPLAY_FRAMES` physics steps, oldest first
+#[derive(Resource, Default)]
+struct SnapshotBuffer(VecDeque<WorldSnapshot>);
+
+// Present while a replay is playing. Virtual time is paused for its
+// length, so the serve and everything else wait until it's over.
+#[derive(Resource)]
+struct Replay {
+    frames: Vec<WorldSnapshot>,
+    // fractional index into `frames`
+    cursor: f32,
+}
+
+// Stand-ins drawn from the snapshots while the real entities are hidden
+#[derive(Component)]
+struct ReplayBall(usize);
+
+#[derive(Component)]
+struct ReplayPaddle(usize);
+
+#[derive(Component)]
+struct ReplayWatermark;
+
+pub struct SlowMotionReplayPlugin;
+
+impl Plugin for SlowMotionReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SnapshotBuffer>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    record_snapshot.after(move_ball).after(move_paddles),
+                    start_replay.after(detect_scoring),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, play_replay.run_if(resource_exists::<Replay>));
+    }
+}
+
+fn record_snapshot(
+    mut buffer: ResMut<SnapshotBuffer>,
+    balls: Query<&Position, With<Ball>>,
+    paddles: Query<(&Position, Has<Player>), With<Paddle>>,
+) {
+    buffer.0.push_back(WorldSnapshot {
+        balls: balls.iter().map(|position| position.0).collect(),
+        paddles: paddles
+            .iter()
+            .map(|(position, is_player)| (position.0, is_player))
+            .collect(),
+    });
+    if buffer.0.len() > REPLAY_FRAMES {
+        buffer.0.pop_front();
+    }
+}
+
+fn start_replay(
+    mut commands: Commands,
+    mut events: EventReader<Scored>,
+    mut buffer: ResMut<SnapshotBuffer>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    // still the balls of the rally that just ended, `reset_ball`'s
+    // despawns haven't been applied yet
+    balls: Query<&HitCount, With<Ball>>,
+    ball_assets: Res<BallAssets>,
+    paddle_assets: Res<PaddleAssets>,
+) {
+    if events.read().last().is_none() {
+        return;
+    }
+    let longest_rally = balls.iter().map(|hits| hits.0).max().unwrap_or(0);
+    if longest_rally < REPLAY_MIN_HITS || buffer.0.is_empty() {
+        return;
+    }
+
+    info!("Replaying a rally of {longest_rally} hits");
+    let frames: Vec<WorldSnapshot> = buffer.0.drain(..).collect();
+
+    let ball_count = frames.iter().map(|frame| frame.balls.len()).max();
+    for index in 0..ball_count.unwrap_or(0) {
+        commands.spawn((ReplayBall(index), ball_assets.bundle()));
+    }
+    for (index, (_, is_player)) in frames[0].paddles.iter().enumerate() {
+        let bundle = if *is_player {
+            paddle_assets.player_bundle()
+        } else {
+            paddle_assets.bundle()
+        };
+        commands.spawn((ReplayPaddle(index), bundle));
+    }
+
+    commands.spawn((
+        TextBundle::from_section(
+            "REPLAY",
+            TextStyle {
+                font_size: 40.,
+                color: Color::srgba(1., 1., 1., 0.5),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            right: Val::Px(20.0),
+            ..default()
+        }),
+        ReplayWatermark,
+    ));
+
+    commands.insert_resource(Replay { frames, cursor: 0. });
+    virtual_time.pause();
+}
+
+fn play_replay(
+    mut commands: Commands,
+    mut replay: ResMut<Replay>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut replay_balls: Query<(&ReplayBall, &mut Transform, &mut Visibility)>,
+    mut replay_paddles: Query<(&ReplayPaddle, &mut Transform), Without<ReplayBall>>,
+    mut real: Query<
+        &mut Visibility,
+        (
+            Or<(With<Ball>, With<Paddle>)>,
+            Without<ReplayBall>,
+            Without<ReplayPaddle>,
+        ),
+    >,
+    stand_ins: Query<Entity, Or<(With<ReplayBall>, With<ReplayPaddle>, With<ReplayWatermark>)>>,
+    time: Res<Time<Real>>,
+) {
+    let last = (replay.frames.len() - 1) as f32;
+    if replay.cursor >= last {
+        for entity in &stand_ins {
+            commands.entity(entity).despawn();
+        }
+        for mut visibility in &mut real {
+            *visibility = Visibility::Inherited;
+        }
+        commands.remove_resource::<Replay>();
+        virtual_time.unpause();
+        return;
+    }
+
+    for mut visibility in &mut real {
+        *visibility = Visibility::Hidden;
+    }
+
+    let index = replay.cursor.floor() as usize;
+    let blend = replay.cursor.fract();
+    let (from, to) = (&replay.frames[index], &replay.frames[index + 1]);
+
+    for (ball, mut transform, mut visibility) in &mut replay_balls {
+        let position = match (from.balls.get(ball.0), to.balls.get(ball.0)) {
+            (Some(from), Some(to)) => Some(from.lerp(*to, blend)),
+            // a ball was served or scored between the two steps
+            (Some(position), None) | (None, Some(position)) => Some(*position),
+            (None, None) => None,
+        };
+        match position {
+            Some(position) => {
+                transform.translation = position.extend(0.);
+                *visibility = Visibility::Visible;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+
+    for (paddle, mut transform) in &mut replay_paddles {
+        if let (Some((from, _)), Some((to, _))) =
+            (from.paddles.get(paddle.0), to.paddles.get(paddle.0))
+        {
+            transform.translation = from.lerp(*to, blend).extend(0.);
+        }
+    }
+
+    let steps = time.delta_seconds() * BASE_TIMESTEP_HZ as f32 * REPLAY_SPEED;
+    replay.cursor = (replay.cursor + steps).min(last);
+}
@@ -0,0 +1,133 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use bevy::prelude::*;
+
+use crate::game_state::{AddGameMode, GameState};
+
+const ROTATION_INTERVAL_SECS: f32 = 30.;
+const ROTATION_SECS: f32 = 2.;
+
+// Game variant where the whole field turns a quarter every 30 seconds
+#[derive(Resource, Default)]
+struct RotatingPlayfield(bool);
+
+// How far the camera is turned, in radians, and how
+// fast it is turning right now
+#[derive(Resource, Default)]
+pub struct PlayfieldRotation {
+    pub angle: f32,
+    speed: f32,
+}
+
+impl PlayfieldRotation {
+    // The player's "up" key moves their paddle towards the top of the
+    // screen, or to the right while the paddles lie on their side
+    pub fn input_sign(&self) -> f32 {
+        let (sin, cos) = self.angle.sin_cos();
+        if cos.abs() >= sin.abs() {
+            cos.signum()
+        } else {
+            sin.signum()
+        }
+    }
+
+    // screen down, in world space
+    pub fn down(&self) -> Vec2 {
+        Vec2::from_angle(self.angle).rotate(Vec2::NEG_Y)
+    }
+}
+
+#[derive(Resource)]
+struct RotationSchedule {
+    timer: Timer,
+    // radians left of the turn under way
+    remaining: f32,
+}
+
+impl Default for RotationSchedule {
+    fn default() -> Self {
+        RotationSchedule {
+            timer: Timer::from_seconds(ROTATION_INTERVAL_SECS, TimerMode::Repeating),
+            remaining: 0.,
+        }
+    }
+}
+
+pub struct RotatingPlayfieldPlugin;
+
+impl Plugin for RotatingPlayfieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RotatingPlayfield>()
+            .init_resource::<PlayfieldRotation>()
+            .init_resource::<RotationSchedule>()
+            .add_game_mode(
+                KeyCode::KeyR,
+                "mode_rotating_playfield",
+                toggle_rotating_playfield,
+            )
+            .add_systems(
+                Update,
+                (
+                    rotate_playfield
+                        .after(toggle_rotating_playfield)
+                        .run_if(in_state(GameState::Playing)),
+                    rotate_camera.after(rotate_playfield),
+                ),
+            );
+    }
+}
+
+fn toggle_rotating_playfield(
+    mut rotating: ResMut<RotatingPlayfield>,
+    mut rotation: ResMut<PlayfieldRotation>,
+    mut schedule: ResMut<RotationSchedule>,
+) {
+    rotating.0 = !rotating.0;
+    info!("Rotating playfield: {}", rotating.0);
+
+    // either way the field starts off upright
+    *rotation = PlayfieldRotation::default();
+    *schedule = RotationSchedule::default();
+}
+
+fn rotate_playfield(
+    rotating: Res<RotatingPlayfield>,
+    mut rotation: ResMut<PlayfieldRotation>,
+    mut schedule: ResMut<RotationSchedule>,
+    time: Res<Time>,
+) {
+    if !rotating.0 {
+        return;
+    }
+
+    if schedule.timer.tick(time.delta()).just_finished() {
+        schedule.remaining = FRAC_PI_2;
+        rotation.speed = FRAC_PI_2 / ROTATION_SECS;
+    }
+    if schedule.remaining <= 0. {
+        return;
+    }
+
+    let step = (rotation.speed * time.delta_seconds()).min(schedule.remaining);
+    rotation.angle += step;
+    schedule.remaining -= step;
+
+    if schedule.remaining <= 0. {
+        // land exactly on the quarter, four turns wrap back to 0°
+        let quarters = (rotation.angle / FRAC_PI_2).round();
+        rotation.angle = (quarters * FRAC_PI_2) % TAU;
+        rotation.speed = 0.;
+    }
+}
+
+fn rotate_camera(
+    rotation: Res<PlayfieldRotation>,
+    mut camera: Query<&mut Transform, With<Camera2d>>,
+) {
+    if !rotation.is_changed() {
+        return;
+    }
+    if let Ok(mut transform) = camera.get_single_mut() {
+        transform.rotation = Quat::from_rotation_z(rotation.angle);
+    }
+}
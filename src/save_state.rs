@@ -0,0 +1,246 @@
+use std::fs;
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use bevy::scene::ron;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::scene::DynamicEntity;
+use serde::de::DeserializeSeed;
+
+use crate::ai::AiDifficulty;
+use crate::rally::RallyLength;
+use crate::team::Team;
+use crate::{
+    Ai, Ball, BallAssets, HitCount, LastHitBy, Paddle, PaddleAssets, PaddleHeightModifiers, Player,
+    Position, PositionHistory, PrevPosition, Score, Shape, Velocity,
+};
+
+const QUICKSAVE_PATH: &str = "quicksave.scn.ron";
+
+// Whether a paddle is the player's, the AI's or a teammate, and on
+// which team
+type PaddleRole = (bool, bool, Option<Team>);
+
+pub struct SaveStatePlugin;
+
+impl Plugin for SaveStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Ball>()
+            .register_type::<Paddle>()
+            .register_type::<Player>()
+            .register_type::<Ai>()
+            .register_type::<Team>()
+            .register_type::<Position>()
+            .register_type::<Velocity>()
+            .register_type::<Shape>()
+            .register_type::<HitCount>()
+            .register_type::<Score>()
+            .register_type::<AiDifficulty>()
+            .register_type::<RallyLength>()
+            .add_systems(
+                Update,
+                (
+                    quicksave.run_if(input_just_pressed(KeyCode::F5)),
+                    quickload.run_if(input_just_pressed(KeyCode::F9)),
+                ),
+            );
+    }
+}
+
+fn quicksave(world: &mut World) {
+    save_game(world, QUICKSAVE_PATH);
+}
+
+fn quickload(world: &mut World) {
+    load_game(world, QUICKSAVE_PATH);
+}
+
+// Only the gameplay state goes into the scene, the meshes and
+// materials are re-attached from `BallAssets` and `PaddleAssets`
+// when loading
+pub fn save_game(world: &mut World, path: &str) {
+    let mut saved = world.query_filtered::<Entity, Or<(With<Ball>, With<Paddle>)>>();
+    let entities: Vec<Entity> = saved.iter(world).collect();
+
+    let scene = DynamicSceneBuilder::from_world(world)
+        .deny_all()
+        .allow::<Ball>()
+        .allow::<Paddle>()
+        .allow::<Player>()
+        .allow::<Ai>()
+        .allow::<Team>()
+        .allow::<Position>()
+        .allow::<Velocity>()
+        .allow::<Shape>()
+        .allow::<HitCount>()
+        .deny_all_resources()
+        .allow_resource::<Score>()
+        .allow_resource::<AiDifficulty>()
+        .allow_resource::<RallyLength>()
+        .extract_entities(entities.into_iter())
+        .extract_resources()
+        .build();
+
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    match scene.serialize(&type_registry) {
+        Ok(serialized) => match fs::write(path, serialized) {
+            Ok(()) => info!("Saved to {path}"),
+            Err(err) => error!("Failed to write {path}: {err}"),
+        },
+        Err(err) => error!("Failed to serialize {path}: {err}"),
+    }
+}
+
+pub fn load_game(world: &mut World, path: &str) {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            error!("Failed to read {path}: {err}");
+            return;
+        }
+    };
+
+    let scene = {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &type_registry,
+        };
+        let deserialized = ron::de::Deserializer::from_str(&contents)
+            .map_err(ron::Error::from)
+            .and_then(|mut deserializer| scene_deserializer.deserialize(&mut deserializer));
+        match deserialized {
+            Ok(scene) => scene,
+            Err(err) => {
+                error!("Failed to deserialize {path}: {err}");
+                return;
+            }
+        }
+    };
+
+    // The saved values are written over the paddles and balls already
+    // there, so whatever else features keep on them, like
+    // `PaddleHeightModifiers`, carries on. Paddles are matched up by who
+    // plays them, balls by count, with spares on either side despawned
+    // or spawned.
+    let mut entity_map = EntityHashMap::default();
+    let mut paddles =
+        world.query_filtered::<(Entity, Has<Player>, Has<Ai>, Option<&Team>), With<Paddle>>();
+    let mut paddles: Vec<(Entity, PaddleRole)> = paddles
+        .iter(world)
+        .map(|(entity, is_player, is_ai, team)| (entity, (is_player, is_ai, team.copied())))
+        .collect();
+    let mut balls = world.query_filtered::<Entity, With<Ball>>();
+    let mut balls: Vec<Entity> = balls.iter(world).collect();
+    for saved in &scene.entities {
+        let existing = if has::<Ball>(saved) {
+            balls.pop()
+        } else {
+            let team = saved
+                .components
+                .iter()
+                .find_map(|component| Team::from_reflect(&**component));
+            let role = (has::<Player>(saved), has::<Ai>(saved), team);
+            paddles
+                .iter()
+                .position(|(_, existing)| *existing == role)
+                .map(|index| paddles.swap_remove(index).0)
+        };
+        if let Some(existing) = existing {
+            entity_map.insert(saved.entity, existing);
+        }
+    }
+    for entity in balls {
+        world.despawn(entity);
+    }
+
+    if let Err(err) = scene.write_to_world(world, &mut entity_map) {
+        error!("Failed to load {path}: {err}");
+        return;
+    }
+
+    let ball_bundle = world.resource::<BallAssets>().bundle();
+    let paddle_assets = world.resource::<PaddleAssets>();
+    let paddle_bundle = paddle_assets.bundle();
+    let player_paddle_bundle = paddle_assets.player_bundle();
+    for entity in entity_map.into_values() {
+        let mut entity = world.entity_mut(entity);
+        if entity.contains::<Ball>() {
+            // runtime-only ball state starts over
+            entity.insert((
+                LastHitBy(None),
+                PositionHistory::default(),
+                PrevPosition::default(),
+            ));
+            if !entity.contains::<Handle<Mesh>>() {
+                entity.insert(ball_bundle.clone());
+            }
+        } else if !entity.contains::<PaddleHeightModifiers>() {
+            // a paddle with nothing to match, from another team mode
+            let bundle = if entity.contains::<Player>() {
+                player_paddle_bundle.clone()
+            } else {
+                paddle_bundle.clone()
+            };
+            entity.insert((bundle, PaddleHeightModifiers::default()));
+        }
+    }
+
+    info!("Loaded {path}");
+}
+
+fn has<T: Reflect + TypePath>(saved: &DynamicEntity) -> bool {
+    saved
+        .components
+        .iter()
+        .any(|component| component.represents::<T>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handicap::HandicapPlugin;
+    use crate::localization::Locale;
+    use crate::test_mode::{headless_app, run_frames};
+    use crate::PADDLE_HEIGHT;
+
+    #[test]
+    fn handicap_still_resizes_after_a_quickload() {
+        let mut app = headless_app(vec![]);
+        app.add_plugins((SaveStatePlugin, HandicapPlugin))
+            .init_resource::<Locale>();
+        run_frames(&mut app, 1);
+        let path = std::env::temp_dir().join("learning_bevy_quickload_test.scn.ron");
+        let path = path.to_str().unwrap();
+        save_game(app.world_mut(), path);
+        load_game(app.world_mut(), path);
+        let _ = fs::remove_file(path);
+
+        // two behind, so the player gets the bigger paddle
+        app.world_mut().resource_mut::<Score>().ai = 2;
+        // one to hand out the bonus, one for the physics to pick it up
+        run_frames(&mut app, 2);
+
+        let mut paddles = app.world_mut().query_filtered::<&Shape, With<Player>>();
+        assert_eq!(paddles.single(app.world()).0.y, PADDLE_HEIGHT * 1.3);
+    }
+
+    #[test]
+    fn difficulty_and_rally_length_are_saved() {
+        let mut app = headless_app(vec![]);
+        app.add_plugins(SaveStatePlugin)
+            .insert_resource(AiDifficulty::Hard)
+            .insert_resource(RallyLength(7));
+        run_frames(&mut app, 1);
+        let path = std::env::temp_dir().join("learning_bevy_checkpoint_test.scn.ron");
+        let path = path.to_str().unwrap();
+        save_game(app.world_mut(), path);
+        app.insert_resource(AiDifficulty::Easy)
+            .insert_resource(RallyLength(0));
+        load_game(app.world_mut(), path);
+        let _ = fs::remove_file(path);
+
+        assert_eq!(*app.world().resource::<AiDifficulty>(), AiDifficulty::Hard);
+        assert_eq!(app.world().resource::<RallyLength>().0, 7);
+    }
+}
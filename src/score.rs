@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ball::{move_ball, Ball};
+use crate::double_width::PlayfieldScaleAnimation;
+use crate::game_state::GameState;
+use crate::settings;
+use crate::toast::Toast;
+use crate::{Position, Velocity};
+
+// |v.y| / |v.x| above which a goal counts as a steep shot, about tan(60°)
+const STEEP_ANGLE_THRESHOLD: f32 = 1.73;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Scorer {
+    Player,
+    Ai,
+}
+
+// Who scored and how many points the goal is worth
+#[derive(Event)]
+pub struct Scored(pub Scorer, pub u32);
+
+// Sent once either side has reached the `WinningScore`
+#[derive(Event, Debug, Copy, Clone)]
+pub struct MatchOver(pub Scorer);
+
+// First to this many points wins the match, set in the settings file.
+// `None` plays on forever.
+#[derive(Resource, Serialize, Deserialize, Clone, Copy)]
+pub struct WinningScore(pub Option<u32>);
+
+impl Default for WinningScore {
+    fn default() -> Self {
+        WinningScore(Some(11))
+    }
+}
+
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+pub struct Score {
+    pub player: u32,
+    pub ai: u32,
+}
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Score>()
+            .insert_resource(settings::load().winning_score)
+            .add_event::<Scored>()
+            .add_event::<MatchOver>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    detect_scoring.after(move_ball),
+                    update_score.after(detect_scoring),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+pub fn detect_scoring(
+    balls: Query<(&Position, &Velocity), With<Ball>>,
+    window: Query<&Window>,
+    playfield: Option<Res<PlayfieldScaleAnimation>>,
+    mut events: EventWriter<Scored>,
+    mut toasts: Option<ResMut<Events<Toast>>>,
+) {
+    // get the window
+    if let Ok(window) = window.get_single() {
+        // the field widens past the window while double width is active
+        let scale = playfield.map_or(1., |playfield| playfield.scale);
+        let window_width = window.resolution.width() * scale;
+        let window_height = window.resolution.height();
+
+        if balls.is_empty() {
+            eprintln!("No ball found in the scene.");
+        }
+
+        // every ball in play can score
+        for (ball_position, ball_velocity) in &balls {
+            if ball_position.0.x > window_width / 2. {
+                // steep shots past the AI are worth double
+                let angle = ball_velocity.0.y.abs() / ball_velocity.0.x.abs();
+                if angle > STEEP_ANGLE_THRESHOLD {
+                    events.send(Scored(Scorer::Player, 2));
+                    if let Some(toasts) = toasts.as_mut() {
+                        toasts.send(Toast("angle_bonus"));
+                    }
+                } else {
+                    events.send(Scored(Scorer::Player, 1));
+                }
+            } else if ball_position.0.x < -window_width / 2. {
+                events.send(Scored(Scorer::Ai, 1));
+            } else if ball_position.0.y.abs() > window_height / 2. {
+                // escaped through a broken gutter, the point goes
+                // to whoever the ball was heading away from
+                if ball_velocity.0.x > 0. {
+                    events.send(Scored(Scorer::Player, 1));
+                } else {
+                    events.send(Scored(Scorer::Ai, 1));
+                }
+            }
+        }
+    } else {
+        eprintln!("No window found in the scene.");
+    }
+}
+
+pub fn update_score(
+    mut score: ResMut<Score>,
+    mut scored_events: EventReader<Scored>,
+    gamepads: Res<Gamepads>,
+    mut rumble_requests: EventWriter<GamepadRumbleRequest>,
+    winning_score: Res<WinningScore>,
+    mut match_over: EventWriter<MatchOver>,
+) {
+    if scored_events.is_empty() {
+        return;
+    }
+
+    for event in scored_events.read() {
+        match event.0 {
+            Scorer::Player => score.player += event.1,
+            Scorer::Ai => score.ai += event.1,
+        }
+
+        // a short kick for our points, a long grumble for theirs
+        let (duration, intensity) = match event.0 {
+            Scorer::Player => (0.15, GamepadRumbleIntensity::strong_motor(0.8)),
+            Scorer::Ai => (0.4, GamepadRumbleIntensity::strong_motor(0.2)),
+        };
+        // with no gamepad connected there is simply nothing to rumble
+        for gamepad in gamepads.iter() {
+            rumble_requests.send(GamepadRumbleRequest::Add {
+                duration: Duration::from_secs_f32(duration),
+                intensity,
+                gamepad,
+            });
+        }
+    }
+    debug!("Score: Player {} - Ai {}", score.player, score.ai);
+
+    // checked once all of this step's points are in, a bonus can
+    // take a side past the winning score rather than onto it
+    if let Some(winning_score) = winning_score.0 {
+        if score.player >= winning_score {
+            match_over.send(MatchOver(Scorer::Player));
+        } else if score.ai >= winning_score {
+            match_over.send(MatchOver(Scorer::Ai));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::AiOpponent;
+    use crate::test_mode::headless_app;
+
+    #[test]
+    fn reaching_the_winning_score_ends_the_match() {
+        // the same point as `test_mode::moving_ai_paddle_away_lets_player_score`
+        let mut app = headless_app(vec![(5, KeyCode::KeyW, true), (35, KeyCode::KeyW, false)]);
+        app.insert_resource(AiOpponent(false))
+            .insert_resource(WinningScore(Some(1)));
+
+        let mut winners = Vec::new();
+        for _ in 0..150 {
+            app.update();
+            let mut match_over = app.world_mut().resource_mut::<Events<MatchOver>>();
+            winners.extend(match_over.drain().map(|event| event.0));
+        }
+
+        assert!(
+            matches!(winners[..], [Scorer::Player]),
+            "the player should win once, not {winners:?}"
+        );
+    }
+}
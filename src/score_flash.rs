@@ -0,0 +1,91 @@
+use bevy::color::Mix;
+use bevy::prelude::*;
+
+use crate::{Scored, Scorer};
+
+const FLASH_FRAMES: u32 = 3;
+const FLASH_FADE_SECS: f32 = 0.5;
+
+// A full-screen flash of the background, held for a few
+// frames and then faded back to the normal clear colour
+#[derive(Resource)]
+struct FlashState {
+    color: Color,
+    timer: Timer,
+    frames_left: u32,
+    // the clear colour from before the flash
+    background: Color,
+    active: bool,
+}
+
+impl Default for FlashState {
+    fn default() -> Self {
+        FlashState {
+            color: Color::BLACK,
+            timer: Timer::from_seconds(FLASH_FADE_SECS, TimerMode::Once),
+            frames_left: 0,
+            background: Color::BLACK,
+            active: false,
+        }
+    }
+}
+
+pub struct ScoreFlashPlugin;
+
+impl Plugin for ScoreFlashPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FlashState>()
+            .add_systems(Update, (start_flash, flash_background.after(start_flash)));
+    }
+}
+
+fn start_flash(
+    mut flash: ResMut<FlashState>,
+    clear_color: Res<ClearColor>,
+    mut events: EventReader<Scored>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+
+    // a goal during a flash keeps the original background
+    if !flash.active {
+        flash.background = clear_color.0;
+    }
+    flash.color = match event.0 {
+        Scorer::Player => Color::srgb(0.1, 0.9, 0.2),
+        Scorer::Ai => Color::srgb(0.9, 0.1, 0.1),
+    };
+    flash.frames_left = FLASH_FRAMES;
+    flash.timer.reset();
+    flash.active = true;
+}
+
+fn flash_background(
+    mut flash: ResMut<FlashState>,
+    mut clear_color: ResMut<ClearColor>,
+    time: Res<Time>,
+) {
+    if !flash.active {
+        return;
+    }
+
+    if flash.frames_left > 0 {
+        flash.frames_left -= 1;
+        clear_color.0 = flash.color;
+        return;
+    }
+
+    flash.timer.tick(time.delta());
+    let faded = flash.timer.fraction();
+    clear_color.0 = flash
+        .color
+        .to_linear()
+        .mix(&flash.background.to_linear(), faded)
+        .into();
+
+    if flash.timer.finished() {
+        clear_color.0 = flash.background;
+        flash.active = false;
+    }
+}
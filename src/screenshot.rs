@@ -0,0 +1,54 @@
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::ScreenshotManager;
+use bevy::window::PrimaryWindow;
+
+use crate::Scored;
+
+const SCREENSHOT_DIR: &str = "screenshots";
+
+// Numbers the screenshots, so several goals within
+// the same second still get a file each
+#[derive(Resource, Default)]
+struct ScreenshotCounter(u32);
+
+pub struct ScreenshotOnScorePlugin;
+
+impl Plugin for ScreenshotOnScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenshotCounter>()
+            .add_systems(Update, screenshot_on_score);
+    }
+}
+
+fn screenshot_on_score(
+    mut events: EventReader<Scored>,
+    mut screenshot_manager: ResMut<ScreenshotManager>,
+    mut counter: ResMut<ScreenshotCounter>,
+    window: Query<Entity, With<PrimaryWindow>>,
+) {
+    if events.read().last().is_none() {
+        return;
+    }
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+
+    if let Err(err) = fs::create_dir_all(SCREENSHOT_DIR) {
+        error!("Failed to create {SCREENSHOT_DIR}: {err}");
+        return;
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    counter.0 += 1;
+    let path = format!("{SCREENSHOT_DIR}/score_{timestamp}_{:03}.png", counter.0);
+
+    match screenshot_manager.save_screenshot_to_disk(window, &path) {
+        Ok(()) => info!("Saving screenshot to {path}"),
+        Err(err) => error!("Failed to take screenshot: {err}"),
+    }
+}
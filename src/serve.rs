@@ -0,0 +1,129 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::key_bindings::KeyBindings;
+use crate::{reset_ball, Ball, BallFrozen, Position, Scored, Scorer, Velocity};
+
+const SERVE_COUNTDOWN_SECS: f32 = 3.;
+const MAX_SERVE_ANGLE: f32 = PI / 6.;
+// radians per second while an arrow key is held
+const AIM_SPEED: f32 = PI / 3.;
+const ARROW_LENGTH: f32 = 60.;
+
+// After the AI scores the player serves, and gets a short
+// countdown to aim the ball before it is launched
+#[derive(Resource)]
+pub struct ServeSelector {
+    countdown: Timer,
+    // from horizontal, positive aims up
+    angle: f32,
+    active: bool,
+}
+
+impl Default for ServeSelector {
+    fn default() -> Self {
+        ServeSelector {
+            countdown: Timer::from_seconds(SERVE_COUNTDOWN_SECS, TimerMode::Once),
+            angle: 0.,
+            active: false,
+        }
+    }
+}
+
+impl ServeSelector {
+    // the player is aiming a serve that hasn't been launched yet
+    pub fn serve_pending(&self) -> bool {
+        self.active
+    }
+}
+
+pub struct ServeSelectorPlugin;
+
+impl Plugin for ServeSelectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ServeSelector>()
+            .add_systems(
+                FixedUpdate,
+                start_serve
+                    .after(reset_ball)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    aim_serve.run_if(in_state(GameState::Playing)),
+                    draw_serve_arrow.after(aim_serve),
+                ),
+            );
+    }
+}
+
+fn start_serve(
+    mut selector: ResMut<ServeSelector>,
+    mut ball_frozen: ResMut<BallFrozen>,
+    mut events: EventReader<Scored>,
+) {
+    if let Some(event) = events.read().last() {
+        if matches!(event.0, Scorer::Ai) {
+            selector.active = true;
+            selector.angle = 0.;
+            selector.countdown.reset();
+            ball_frozen.0 = true;
+        }
+    }
+}
+
+pub fn aim_serve(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut selector: ResMut<ServeSelector>,
+    mut ball_frozen: ResMut<BallFrozen>,
+    mut balls: Query<&mut Velocity, With<Ball>>,
+    time: Res<Time>,
+) {
+    if !selector.active {
+        return;
+    }
+
+    if keyboard_input.pressed(KeyCode::ArrowLeft) {
+        selector.angle += AIM_SPEED * time.delta_seconds();
+    }
+    if keyboard_input.pressed(KeyCode::ArrowRight) {
+        selector.angle -= AIM_SPEED * time.delta_seconds();
+    }
+    selector.angle = selector.angle.clamp(-MAX_SERVE_ANGLE, MAX_SERVE_ANGLE);
+
+    let launch = selector.countdown.tick(time.delta()).finished()
+        || keyboard_input.just_pressed(bindings.serve);
+    if !launch {
+        return;
+    }
+
+    // keep the speed of the normal serve, only the direction is ours
+    for mut velocity in &mut balls {
+        let speed = velocity.0.length();
+        velocity.0 = Vec2::from_angle(selector.angle) * speed;
+    }
+    selector.active = false;
+    ball_frozen.0 = false;
+    info!("Served at {:.0}°", selector.angle.to_degrees());
+}
+
+// the arrow shrinks as the countdown runs out
+fn draw_serve_arrow(
+    selector: Res<ServeSelector>,
+    balls: Query<&Position, With<Ball>>,
+    mut gizmos: Gizmos,
+) {
+    if !selector.active {
+        return;
+    }
+
+    let length = ARROW_LENGTH * selector.countdown.fraction_remaining();
+    for position in &balls {
+        let tip = position.0 + Vec2::from_angle(selector.angle) * length;
+        gizmos.arrow_2d(position.0, tip, Color::srgb_u8(255, 200, 50));
+    }
+}
@@ -0,0 +1,60 @@
+use std::fs;
+
+use bevy::log::{error, warn};
+use bevy::scene::ron;
+use serde::{Deserialize, Serialize};
+
+use crate::key_bindings::KeyBindings;
+use crate::localization::Locale;
+use crate::paddle_customization::PaddleShape;
+use crate::profile::PlayerProfile;
+use crate::rally::RallySpeedUp;
+use crate::score::WinningScore;
+
+const SETTINGS_PATH: &str = "settings.ron";
+
+// Everything persisted between runs. Missing fields fall back to
+// their defaults so older settings files keep loading.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Settings {
+    pub profile: PlayerProfile,
+    pub locale: Locale,
+    pub glitch_effect: bool,
+    pub tutorial_completed: bool,
+    // monitor name, `None` leaves the window where the OS puts it
+    pub monitor: Option<String>,
+    pub mouse_mode: bool,
+    pub color_blind_mode: bool,
+    pub key_bindings: KeyBindings,
+    pub paddle_shape: PaddleShape,
+    pub rally_speed_up: RallySpeedUp,
+    pub winning_score: WinningScore,
+}
+
+pub fn load() -> Settings {
+    match fs::read_to_string(SETTINGS_PATH) {
+        Ok(contents) => ron::from_str(&contents).unwrap_or_else(|err| {
+            warn!("Failed to parse {SETTINGS_PATH}, using defaults: {err}");
+            Settings::default()
+        }),
+        // no settings saved yet
+        Err(_) => Settings::default(),
+    }
+}
+
+// Re-reads the file before writing so features only touch their own fields
+pub fn update(change: impl FnOnce(&mut Settings)) {
+    let mut settings = load();
+    change(&mut settings);
+
+    let serialized = ron::ser::to_string_pretty(&settings, ron::ser::PrettyConfig::default());
+    match serialized {
+        Ok(serialized) => {
+            if let Err(err) = fs::write(SETTINGS_PATH, serialized) {
+                error!("Failed to write {SETTINGS_PATH}: {err}");
+            }
+        }
+        Err(err) => error!("Failed to serialize settings: {err}"),
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+const MIN_SIMULATION_SPEED: f32 = 0.1;
+const MAX_SIMULATION_SPEED: f32 = 4.;
+
+// Scales virtual time, which drives both the fixed physics
+// step and every `Timer` ticked from `Res<Time>`
+#[derive(Resource)]
+pub struct SimulationSpeed(pub f32);
+
+impl Default for SimulationSpeed {
+    fn default() -> Self {
+        SimulationSpeed(1.)
+    }
+}
+
+pub struct SimulationSpeedPlugin;
+
+impl Plugin for SimulationSpeedPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SimulationSpeed>().add_systems(
+            Update,
+            (
+                change_simulation_speed,
+                apply_simulation_speed.after(change_simulation_speed),
+            ),
+        );
+    }
+}
+
+fn change_simulation_speed(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut simulation_speed: ResMut<SimulationSpeed>,
+) {
+    let factor = if keyboard_input.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        0.5
+    } else if keyboard_input.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]) {
+        2.
+    } else {
+        return;
+    };
+
+    simulation_speed.0 =
+        (simulation_speed.0 * factor).clamp(MIN_SIMULATION_SPEED, MAX_SIMULATION_SPEED);
+    info!("Simulation speed: {}", simulation_speed.0);
+}
+
+fn apply_simulation_speed(
+    simulation_speed: Res<SimulationSpeed>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if simulation_speed.is_changed() {
+        virtual_time.set_relative_speed(simulation_speed.0);
+    }
+}
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+const SLIPPERY_FRICTION: f32 = 0.85;
+
+// How much of a paddle's speed survives each step once its key is
+// released. 1.0 is special cased as the classic instant stop.
+#[derive(Resource)]
+pub struct PaddlePhysics {
+    pub friction: f32,
+}
+
+impl Default for PaddlePhysics {
+    fn default() -> Self {
+        PaddlePhysics { friction: 1. }
+    }
+}
+
+pub struct SlipperyFloorPlugin;
+
+impl Plugin for SlipperyFloorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PaddlePhysics>()
+            .add_systems(Update, toggle_slippery_floor);
+    }
+}
+
+// There is no game mode selection yet, so F11 switches the floor
+fn toggle_slippery_floor(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut physics: ResMut<PaddlePhysics>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F11) {
+        physics.friction = if physics.friction < 1. {
+            1.
+        } else {
+            SLIPPERY_FRICTION
+        };
+        info!("Paddle friction: {}", physics.friction);
+    }
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::simulation_speed::SimulationSpeed;
+use crate::{handle_collisions, Ball, Paddle, Position, Shape, Velocity, PADDLE_WIDTH};
+
+// how close the ball has to come to a paddle it slips past
+const NEAR_MISS_DISTANCE: f32 = PADDLE_WIDTH * 2.0;
+const SLOW_MOTION_SPEED: f32 = 0.2;
+const SLOW_MOTION_PITCH: f32 = 0.6;
+const SLOW_MOTION_SECS: f32 = 0.5;
+
+// Sent when a ball slips just past a paddle
+#[derive(Event)]
+pub struct NearMiss;
+
+// Counts down in real time, since virtual time is what we slow down.
+// Holds the speed to snap back to afterwards.
+#[derive(Resource, Default)]
+struct SlowMotion {
+    remaining: Option<Timer>,
+    previous_speed: f32,
+}
+
+pub struct SlowMotionOnNearMissPlugin;
+
+impl Plugin for SlowMotionOnNearMissPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NearMiss>()
+            .init_resource::<SlowMotion>()
+            .add_systems(
+                FixedUpdate,
+                detect_near_misses
+                    .after(handle_collisions)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, end_slow_motion);
+    }
+}
+
+fn detect_near_misses(
+    balls: Query<(&Position, &Velocity, &Shape), With<Ball>>,
+    paddles: Query<(&Position, &Shape), With<Paddle>>,
+    mut slow_motion: ResMut<SlowMotion>,
+    mut simulation_speed: ResMut<SimulationSpeed>,
+    mut near_misses: EventWriter<NearMiss>,
+    sinks: Query<&AudioSink>,
+) {
+    if slow_motion.remaining.is_some() {
+        return;
+    }
+
+    for (ball_position, ball_velocity, ball_shape) in &balls {
+        for (paddle_position, paddle_shape) in &paddles {
+            // a returned ball is heading back towards the middle
+            if ball_velocity.0.x.signum() != paddle_position.0.x.signum() {
+                continue;
+            }
+
+            // did the ball cross the paddle's line this step?
+            let previous_x = ball_position.0.x - ball_velocity.0.x;
+            let crossed = (previous_x - paddle_position.0.x).signum()
+                != (ball_position.0.x - paddle_position.0.x).signum();
+            if !crossed {
+                continue;
+            }
+
+            let gap = (ball_position.0.y - paddle_position.0.y).abs()
+                - paddle_shape.0.y / 2.
+                - ball_shape.0.x;
+            if gap <= NEAR_MISS_DISTANCE {
+                info!("Near miss by {gap:.1}px");
+                near_misses.send(NearMiss);
+                slow_motion.remaining =
+                    Some(Timer::from_seconds(SLOW_MOTION_SECS, TimerMode::Once));
+                slow_motion.previous_speed = simulation_speed.0;
+                simulation_speed.0 = SLOW_MOTION_SPEED;
+                for sink in &sinks {
+                    sink.set_speed(SLOW_MOTION_PITCH);
+                }
+                return;
+            }
+        }
+    }
+}
+
+fn end_slow_motion(
+    mut slow_motion: ResMut<SlowMotion>,
+    mut simulation_speed: ResMut<SimulationSpeed>,
+    sinks: Query<&AudioSink>,
+    time: Res<Time<Real>>,
+) {
+    let previous_speed = slow_motion.previous_speed;
+    let Some(timer) = &mut slow_motion.remaining else {
+        return;
+    };
+
+    if timer.tick(time.delta()).finished() {
+        slow_motion.remaining = None;
+        simulation_speed.0 = previous_speed;
+        for sink in &sinks {
+            sink.set_speed(1.);
+        }
+    }
+}
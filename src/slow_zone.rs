@@ -0,0 +1,135 @@
+use bevy::math::bounding::{BoundingCircle, IntersectsVolume};
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+use rand::random;
+
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::{handle_collisions, Ball, BallFrozen, Position, Shape, Velocity};
+
+const SLOW_ZONE_RADIUS: f32 = 40.;
+const SLOW_ZONE_SPAWN_SECS: f32 = 20.;
+const SLOW_ZONE_SECS: f32 = 8.;
+const SLOW_ZONE_FACTOR: f32 = 0.5;
+
+// Despawns itself when the timer runs out
+#[derive(Component)]
+struct SlowZone(Timer);
+
+// On a ball inside at least one zone. Overlapping zones
+// only slow it once, and leaving the last one undoes it.
+#[derive(Component)]
+struct Slowed;
+
+#[derive(Resource)]
+struct SlowZoneSpawnTimer(Timer);
+
+impl Default for SlowZoneSpawnTimer {
+    fn default() -> Self {
+        SlowZoneSpawnTimer(Timer::from_seconds(
+            SLOW_ZONE_SPAWN_SECS,
+            TimerMode::Repeating,
+        ))
+    }
+}
+
+#[derive(Resource)]
+struct SlowZoneAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<ColorMaterial>,
+}
+
+pub struct SlowZonePlugin;
+
+impl Plugin for SlowZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SlowZoneSpawnTimer>()
+            .add_systems(Startup, load_slow_zone_assets)
+            .add_systems(OnEnter(InMatch), reset_resource::<SlowZoneSpawnTimer>)
+            .add_systems(
+                Update,
+                (spawn_slow_zones, despawn_slow_zones).run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                FixedUpdate,
+                check_slow_zones
+                    .after(handle_collisions)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn load_slow_zone_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.insert_resource(SlowZoneAssets {
+        mesh: meshes.add(Circle::new(SLOW_ZONE_RADIUS)),
+        material: materials.add(ColorMaterial::from_color(Color::srgba(0.2, 0.4, 1., 0.3))),
+    });
+}
+
+fn spawn_slow_zones(
+    mut commands: Commands,
+    mut timer: ResMut<SlowZoneSpawnTimer>,
+    slow_zone_assets: Res<SlowZoneAssets>,
+    ball_frozen: Res<BallFrozen>,
+    window: Query<&Window>,
+    time: Res<Time>,
+) {
+    if ball_frozen.0 || !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    if let Ok(window) = window.get_single() {
+        // the middle half of the field, like powerups
+        let x = (random::<f32>() - 0.5) * window.resolution.width() / 2.;
+        let y = (random::<f32>() - 0.5) * window.resolution.height() / 2.;
+
+        commands.spawn((
+            SlowZone(Timer::from_seconds(SLOW_ZONE_SECS, TimerMode::Once)),
+            StateScoped(InMatch),
+            Position(Vec2::new(x, y)),
+            MaterialMesh2dBundle {
+                mesh: slow_zone_assets.mesh.clone().into(),
+                material: slow_zone_assets.material.clone(),
+                transform: Transform::from_xyz(x, y, 0.),
+                ..default()
+            },
+        ));
+    }
+}
+
+fn despawn_slow_zones(
+    mut commands: Commands,
+    mut zones: Query<(Entity, &mut SlowZone)>,
+    time: Res<Time>,
+) {
+    for (entity, mut zone) in &mut zones {
+        if zone.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn check_slow_zones(
+    mut commands: Commands,
+    mut balls: Query<(Entity, &Position, &Shape, &mut Velocity, Has<Slowed>), With<Ball>>,
+    zones: Query<&Position, (With<SlowZone>, Without<Ball>)>,
+) {
+    for (entity, position, shape, mut velocity, slowed) in &mut balls {
+        let ball_circle = BoundingCircle::new(position.0, shape.0.x);
+        let inside = zones.iter().any(|zone_position| {
+            ball_circle.intersects(&BoundingCircle::new(zone_position.0, SLOW_ZONE_RADIUS))
+        });
+
+        // only the speed changes, the direction is kept
+        if inside && !slowed {
+            velocity.0 *= SLOW_ZONE_FACTOR;
+            commands.entity(entity).insert(Slowed);
+        } else if !inside && slowed {
+            velocity.0 /= SLOW_ZONE_FACTOR;
+            commands.entity(entity).remove::<Slowed>();
+        }
+    }
+}
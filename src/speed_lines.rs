@@ -0,0 +1,47 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::{Ball, Position, Shape, Velocity, MAX_BALL_SPEED};
+
+const SPEED_LINE_THRESHOLD: f32 = MAX_BALL_SPEED * 0.7;
+const SPEED_LINE_COUNT: usize = 8;
+
+pub struct SpeedLinesPlugin;
+
+impl Plugin for SpeedLinesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, draw_speed_lines);
+    }
+}
+
+// Radial lines out from fast balls, fading in past the threshold.
+// Drawn as gizmos so nothing needs to be spawned.
+fn draw_speed_lines(
+    balls: Query<(&Position, &Velocity, &Shape), With<Ball>>,
+    window: Query<&Window>,
+    mut gizmos: Gizmos,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    // long enough to reach a screen edge from anywhere
+    let length = window.resolution.size().length();
+
+    for (position, velocity, shape) in &balls {
+        let speed = velocity.0.length();
+        if speed <= SPEED_LINE_THRESHOLD {
+            continue;
+        }
+
+        let alpha = ((speed - SPEED_LINE_THRESHOLD) / (MAX_BALL_SPEED - SPEED_LINE_THRESHOLD))
+            .clamp(0., 1.);
+        let color = Color::srgba(1., 1., 1., alpha);
+        for i in 0..SPEED_LINE_COUNT {
+            let direction = Vec2::from_angle(i as f32 * TAU / SPEED_LINE_COUNT as f32);
+            // start clear of the ball so it stays readable
+            let start = position.0 + direction * shape.0.x * 3.;
+            gizmos.line_2d(start, position.0 + direction * length, color);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+
+const TITLE: &str = "BEVY PONG";
+const SPLASH_SECS: f32 = 2.5;
+// one letter starts fading in every this many seconds
+const LETTER_SECS: f32 = 0.2;
+// after the last letter the title grows and fades away
+const OUTRO_START_SECS: f32 = TITLE.len() as f32 * LETTER_SECS;
+const OUTRO_SCALE: f32 = 1.5;
+
+// Runs on real time, as the game is paused underneath
+#[derive(Resource)]
+struct SplashTimer(Timer);
+
+// Set to finish as the letter has fully faded in, so
+// later letters have longer timers and appear later
+#[derive(Component)]
+struct FadeIn(Timer);
+
+#[derive(Component)]
+struct SplashScreen;
+
+#[derive(Component)]
+struct SplashTitle;
+
+pub struct SplashScreenPlugin;
+
+impl Plugin for SplashScreenPlugin {
+    fn build(&self, app: &mut App) {
+        // not worth the wait while developing
+        let skip = cfg!(debug_assertions) || std::env::args().any(|arg| arg == "--skip-splash");
+        if skip {
+            return;
+        }
+
+        app.insert_resource(SplashTimer(Timer::from_seconds(
+            SPLASH_SECS,
+            TimerMode::Once,
+        )))
+        .add_systems(Startup, spawn_splash_screen)
+        .add_systems(
+            Update,
+            (fade_in_letters, play_splash_outro.after(fade_in_letters))
+                .run_if(resource_exists::<SplashTimer>),
+        );
+    }
+}
+
+fn spawn_splash_screen(mut commands: Commands, mut virtual_time: ResMut<Time<Virtual>>) {
+    // nothing moves until the splash is gone
+    virtual_time.pause();
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::BLACK.into(),
+                z_index: ZIndex::Global(100),
+                ..default()
+            },
+            SplashScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((NodeBundle::default(), SplashTitle))
+                .with_children(|title| {
+                    for (i, letter) in TITLE.chars().enumerate() {
+                        title.spawn((
+                            TextBundle::from_section(
+                                letter.to_string(),
+                                TextStyle {
+                                    font_size: 90.,
+                                    color: Color::srgba(1., 1., 1., 0.),
+                                    ..default()
+                                },
+                            ),
+                            FadeIn(Timer::from_seconds(
+                                (i + 1) as f32 * LETTER_SECS,
+                                TimerMode::Once,
+                            )),
+                        ));
+                    }
+                });
+        });
+}
+
+fn fade_in_letters(mut letters: Query<(&mut FadeIn, &mut Text)>, time: Res<Time<Real>>) {
+    for (mut fade_in, mut text) in &mut letters {
+        if fade_in.0.finished() {
+            continue;
+        }
+        fade_in.0.tick(time.delta());
+
+        let started = fade_in.0.duration().as_secs_f32() - LETTER_SECS;
+        let alpha = ((fade_in.0.elapsed_secs() - started) / LETTER_SECS).clamp(0., 1.);
+        text.sections[0].style.color.set_alpha(alpha);
+    }
+}
+
+fn play_splash_outro(
+    mut commands: Commands,
+    mut timer: ResMut<SplashTimer>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut splash: Query<(Entity, &mut BackgroundColor), With<SplashScreen>>,
+    mut title: Query<&mut Transform, With<SplashTitle>>,
+    mut letters: Query<&mut Text, With<FadeIn>>,
+    time: Res<Time<Real>>,
+) {
+    let Ok((entity, mut background)) = splash.get_single_mut() else {
+        return;
+    };
+
+    if timer.0.tick(time.delta()).finished() {
+        commands.entity(entity).despawn_recursive();
+        commands.remove_resource::<SplashTimer>();
+        virtual_time.unpause();
+        return;
+    }
+
+    let elapsed = timer.0.elapsed_secs();
+    if elapsed < OUTRO_START_SECS {
+        return;
+    }
+
+    let progress = (elapsed - OUTRO_START_SECS) / (SPLASH_SECS - OUTRO_START_SECS);
+    if let Ok(mut transform) = title.get_single_mut() {
+        transform.scale = Vec3::splat(1. + progress * (OUTRO_SCALE - 1.));
+    }
+    for mut text in &mut letters {
+        text.sections[0].style.color.set_alpha(1. - progress);
+    }
+    background.0.set_alpha(1. - progress);
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::game_state::{reset_resource, AddGameMode, GameState, InMatch};
+use crate::localization::{t, Locale};
+use crate::powerups::{PowerupCollected, PowerupKind};
+use crate::{Ai, Player, Scored, Scorer};
+
+const STREAK: u32 = 3;
+const ON_FIRE_STREAK: u32 = 5;
+const STREAK_BANNER_SECS: f32 = 1.5;
+
+// Points in a row for each side since the other last scored
+#[derive(Resource, Default)]
+pub struct Streak {
+    pub player: u32,
+    pub ai: u32,
+}
+
+// Whether reaching a streak also hands out a free powerup
+#[derive(Resource, Default)]
+struct StreakPowerups(bool);
+
+#[derive(Component)]
+struct StreakBanner(Timer);
+
+pub struct StreakPlugin;
+
+impl Plugin for StreakPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Streak>()
+            .init_resource::<StreakPowerups>()
+            .add_systems(OnEnter(InMatch), reset_resource::<Streak>)
+            .add_game_mode(
+                KeyCode::KeyS,
+                "mode_streak_powerups",
+                toggle_streak_powerups,
+            )
+            .add_systems(
+                Update,
+                (
+                    count_streaks.run_if(in_state(GameState::Playing)),
+                    despawn_streak_banner,
+                ),
+            );
+    }
+}
+
+fn toggle_streak_powerups(mut streak_powerups: ResMut<StreakPowerups>) {
+    streak_powerups.0 = !streak_powerups.0;
+    info!("Streak powerups: {}", streak_powerups.0);
+}
+
+fn count_streaks(
+    mut commands: Commands,
+    mut events: EventReader<Scored>,
+    mut streak: ResMut<Streak>,
+    mut collected: EventWriter<PowerupCollected>,
+    streak_powerups: Res<StreakPowerups>,
+    banners: Query<Entity, With<StreakBanner>>,
+    player: Query<Entity, With<Player>>,
+    ai: Query<Entity, With<Ai>>,
+    locale: Res<Locale>,
+) {
+    for event in events.read() {
+        let (streak, paddle) = match event.0 {
+            Scorer::Player => {
+                streak.ai = 0;
+                streak.player += 1;
+                (streak.player, player.get_single())
+            }
+            Scorer::Ai => {
+                streak.player = 0;
+                streak.ai += 1;
+                (streak.ai, ai.get_single())
+            }
+        };
+
+        let key = match streak {
+            STREAK => "streak",
+            ON_FIRE_STREAK => "on_fire",
+            _ => continue,
+        };
+
+        if streak == STREAK && streak_powerups.0 {
+            if let Ok(paddle) = paddle {
+                let index = rand::thread_rng().gen_range(0..PowerupKind::ALL.len());
+                collected.send(PowerupCollected {
+                    kind: PowerupKind::ALL[index],
+                    paddle,
+                });
+            }
+        }
+
+        // a new banner replaces any still showing
+        for entity in &banners {
+            commands.entity(entity).despawn();
+        }
+        commands.spawn((
+            TextBundle::from_section(
+                t(key, &locale),
+                TextStyle {
+                    font_size: 80.,
+                    color: Color::srgb_u8(255, 120, 30),
+                    ..default()
+                },
+            )
+            .with_text_justify(JustifyText::Center)
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(30.),
+                left: Val::Percent(35.),
+                ..default()
+            }),
+            StreakBanner(Timer::from_seconds(STREAK_BANNER_SECS, TimerMode::Once)),
+            StateScoped(InMatch),
+        ));
+    }
+}
+
+fn despawn_streak_banner(
+    mut commands: Commands,
+    mut banners: Query<(Entity, &mut StreakBanner)>,
+    time: Res<Time>,
+) {
+    for (entity, mut banner) in &mut banners {
+        if banner.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+
+use crate::game_state::{GameState, InMatch};
+use crate::localization::{t, Locale};
+use crate::{
+    handle_collisions, update_score, Ai, Ball, HitCount, LastHitBy, Paddle, Player, Position,
+    Score, Scored, Scorer, Shape,
+};
+
+const BONUS_TEXT_SECS: f32 = 1.;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum PaddleZone {
+    Top,
+    Middle,
+    Bottom,
+}
+
+impl PaddleZone {
+    fn multiplier(self) -> f32 {
+        match self {
+            PaddleZone::Middle => 2.,
+            PaddleZone::Top | PaddleZone::Bottom => 1.,
+        }
+    }
+}
+
+// The zone a paddle last struck the ball with, which
+// multiplies the point if that ball goes on to score
+#[derive(Component)]
+struct ZoneBonus {
+    zone: PaddleZone,
+    multiplier: f32,
+}
+
+// Challenge mode, off by default
+#[derive(Resource, Default)]
+struct TargetZoneMode(bool);
+
+#[derive(Component)]
+struct BonusText(Timer);
+
+pub struct TargetZonePlugin;
+
+impl Plugin for TargetZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TargetZoneMode>()
+            .add_systems(
+                Update,
+                (
+                    toggle_target_zones,
+                    draw_zone_boundaries,
+                    despawn_bonus_text,
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    check_hit_zone.after(handle_collisions),
+                    award_zone_bonus.after(update_score),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn toggle_target_zones(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut mode: ResMut<TargetZoneMode>,
+    paddles: Query<Entity, With<ZoneBonus>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F10) {
+        mode.0 = !mode.0;
+        info!("Target zones: {}", mode.0);
+
+        for entity in &paddles {
+            commands.entity(entity).remove::<ZoneBonus>();
+        }
+    }
+}
+
+fn draw_zone_boundaries(
+    mode: Res<TargetZoneMode>,
+    paddles: Query<(&Position, &Shape), With<Paddle>>,
+    mut gizmos: Gizmos,
+) {
+    if !mode.0 {
+        return;
+    }
+
+    for (position, shape) in &paddles {
+        let half_width = shape.0.x / 2.;
+        for offset in [-shape.0.y / 6., shape.0.y / 6.] {
+            let y = position.0.y + offset;
+            gizmos.line_2d(
+                Vec2::new(position.0.x - half_width, y),
+                Vec2::new(position.0.x + half_width, y),
+                Color::BLACK,
+            );
+        }
+    }
+}
+
+fn check_hit_zone(
+    mut commands: Commands,
+    mode: Res<TargetZoneMode>,
+    locale: Res<Locale>,
+    balls: Query<(&Position, &HitCount, &LastHitBy), (With<Ball>, Changed<HitCount>)>,
+    paddles: Query<(&Position, &Shape), With<Paddle>>,
+) {
+    if !mode.0 {
+        return;
+    }
+
+    for (ball_position, hit_count, last_hit_by) in &balls {
+        // freshly served balls haven't hit anything
+        if hit_count.0 == 0 {
+            continue;
+        }
+        let Some(paddle) = last_hit_by.0 else {
+            continue;
+        };
+        let Ok((paddle_position, paddle_shape)) = paddles.get(paddle) else {
+            continue;
+        };
+
+        let offset = ball_position.0.y - paddle_position.0.y;
+        let zone = if offset > paddle_shape.0.y / 6. {
+            PaddleZone::Top
+        } else if offset < -paddle_shape.0.y / 6. {
+            PaddleZone::Bottom
+        } else {
+            PaddleZone::Middle
+        };
+
+        commands.entity(paddle).insert(ZoneBonus {
+            zone,
+            multiplier: zone.multiplier(),
+        });
+
+        if zone == PaddleZone::Middle {
+            commands.spawn((
+                TextBundle::from_section(
+                    format!("{} ×{}", t("bonus", &locale), zone.multiplier()),
+                    TextStyle {
+                        font_size: 24.,
+                        color: Color::srgb_u8(255, 200, 50),
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(92.0),
+                    left: Val::Px(10.0),
+                    ..default()
+                }),
+                BonusText(Timer::from_seconds(BONUS_TEXT_SECS, TimerMode::Once)),
+                StateScoped(InMatch),
+            ));
+        }
+    }
+}
+
+// `update_score` has already counted the point once,
+// this adds whatever the multiplier earns on top
+fn award_zone_bonus(
+    mut commands: Commands,
+    mut score: ResMut<Score>,
+    mut scored_events: EventReader<Scored>,
+    paddles: Query<(Entity, &ZoneBonus, Has<Player>, Has<Ai>)>,
+) {
+    for event in scored_events.read() {
+        for (_, bonus, is_player, is_ai) in &paddles {
+            let scorer_paddle = match event.0 {
+                Scorer::Player => is_player,
+                Scorer::Ai => is_ai,
+            };
+            if scorer_paddle && bonus.zone == PaddleZone::Middle {
+                let extra = (bonus.multiplier - 1.) as u32;
+                match event.0 {
+                    Scorer::Player => score.player += extra,
+                    Scorer::Ai => score.ai += extra,
+                }
+                info!("Zone bonus: +{extra}");
+            }
+        }
+
+        // the next rally starts without any bonus
+        for (entity, ..) in &paddles {
+            commands.entity(entity).remove::<ZoneBonus>();
+        }
+    }
+}
+
+fn despawn_bonus_text(
+    mut commands: Commands,
+    mut texts: Query<(Entity, &mut BonusText)>,
+    time: Res<Time>,
+) {
+    for (entity, mut text) in &mut texts {
+        if text.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
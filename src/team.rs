@@ -0,0 +1,155 @@
+use bevy::math::bounding::{Aabb2d, IntersectsVolume};
+use bevy::prelude::*;
+
+use crate::ai::AiOpponent;
+use crate::game_state::GameState;
+use crate::post_score_freeze::post_score_freeze_over;
+use crate::rotating_playfield::PlayfieldRotation;
+use crate::{
+    apply_paddle_friction, detect_collisions, move_paddles, Paddle, PaddleAssets, PaddleBundle,
+    Position, Shape, Velocity, PADDLE_SPEED,
+};
+
+// 2v2, with a second paddle on each side below the usual one. Picked
+// at launch with `--team-mode`, since `spawn_paddles` sets it up.
+#[derive(Resource, Default)]
+pub struct TeamMode(pub bool);
+
+// Which side a paddle plays for in team mode, A being the player's
+#[derive(Component, Reflect, Copy, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum Team {
+    A,
+    B,
+}
+
+// The second paddle of a team. It isn't a `Player` or `Ai`, so things
+// that look for "the" player's paddle keep finding the first one.
+#[derive(Component)]
+struct Teammate {
+    up: KeyCode,
+    down: KeyCode,
+}
+
+pub struct TeamPlugin;
+
+impl Plugin for TeamPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TeamMode(std::env::args().any(|arg| arg == "--team-mode")))
+            .add_systems(
+                FixedUpdate,
+                (
+                    handle_teammate_input
+                        .after(apply_paddle_friction)
+                        .before(move_paddles)
+                        .run_if(post_score_freeze_over),
+                    separate_teammates
+                        .after(move_paddles)
+                        .before(detect_collisions),
+                )
+                    .run_if(team_mode)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn team_mode(mode: Res<TeamMode>) -> bool {
+    mode.0
+}
+
+// Called by `spawn_paddles` once the usual paddles are placed in
+// the top half, to add one per team in the bottom half
+pub fn spawn_teammates(
+    commands: &mut Commands,
+    paddle_assets: &PaddleAssets,
+    left_x: f32,
+    right_x: f32,
+    y: f32,
+) {
+    // the keys on the far side of the keyboard from each team's first player
+    commands.spawn((
+        Team::A,
+        Teammate {
+            up: KeyCode::ArrowUp,
+            down: KeyCode::ArrowDown,
+        },
+        PaddleBundle::new(left_x, y),
+        paddle_assets.player_bundle(),
+    ));
+    commands.spawn((
+        Team::B,
+        Teammate {
+            up: KeyCode::Numpad8,
+            down: KeyCode::Numpad2,
+        },
+        PaddleBundle::new(right_x, y),
+        paddle_assets.bundle(),
+    ));
+}
+
+// Team B's teammate is left to `ai_paddle_control` while the AI plays
+fn handle_teammate_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    rotation: Res<PlayfieldRotation>,
+    ai_opponent: Res<AiOpponent>,
+    mut teammates: Query<(&Teammate, &Team, &mut Velocity)>,
+) {
+    let speed = PADDLE_SPEED * rotation.input_sign();
+
+    for (teammate, team, mut velocity) in &mut teammates {
+        if ai_opponent.0 && *team == Team::B {
+            continue;
+        }
+        if keyboard_input.pressed(teammate.up) {
+            velocity.0.y = speed;
+        } else if keyboard_input.pressed(teammate.down) {
+            velocity.0.y = -speed;
+        }
+    }
+}
+
+// Teammates block each other rather than overlapping. Whichever one
+// moved into the other gives way, so one resting against a gutter
+// can't be shoved through it.
+fn separate_teammates(mut paddles: Query<(&Team, &mut Position, &Velocity, &Shape), With<Paddle>>) {
+    let mut pairs = paddles.iter_combinations_mut();
+    while let Some([first, second]) = pairs.fetch_next() {
+        let (team, mut first_position, first_velocity, first_shape) = first;
+        let (other_team, mut second_position, second_velocity, second_shape) = second;
+        if team != other_team {
+            continue;
+        }
+
+        let first_box = Aabb2d::new(first_position.0, first_shape.0 / 2.);
+        let second_box = Aabb2d::new(second_position.0, second_shape.0 / 2.);
+        if !first_box.intersects(&second_box) {
+            continue;
+        }
+
+        let first_is_upper = first_position.0.y >= second_position.0.y;
+        let (upper, upper_velocity, lower, lower_velocity) = if first_is_upper {
+            (
+                &mut first_position,
+                first_velocity,
+                &mut second_position,
+                second_velocity,
+            )
+        } else {
+            (
+                &mut second_position,
+                second_velocity,
+                &mut first_position,
+                first_velocity,
+            )
+        };
+        let overlap = (first_shape.0.y + second_shape.0.y) / 2. - (upper.0.y - lower.0.y);
+
+        let upper_share = match (upper_velocity.0.y < 0., lower_velocity.0.y > 0.) {
+            (true, false) => 1.,
+            (false, true) => 0.,
+            _ => 0.5,
+        };
+        upper.0.y += overlap * upper_share;
+        lower.0.y -= overlap * (1. - upper_share);
+    }
+}
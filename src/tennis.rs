@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+
+use crate::game_state::{reset_resource, AddGameMode, InMatch};
+use crate::score::{MatchOver, Scorer, WinningScore};
+use crate::Score;
+
+// a game is the usual first to 11, won by two
+const POINTS_PER_GAME: u32 = 11;
+const GAMES_PER_SET: u32 = 6;
+// best of three
+const SETS_TO_WIN: u32 = 2;
+
+// Scoring variant where points make up games and games make up sets
+#[derive(Resource, Default)]
+struct TennisSets(bool);
+
+// `Score` holds the points of the game being played,
+// this holds everything above that
+#[derive(Resource, Default)]
+struct TennisScore {
+    // games per finished set, player first
+    finished_sets: Vec<(u32, u32)>,
+    player_games: u32,
+    ai_games: u32,
+}
+
+impl TennisScore {
+    fn sets_won(&self) -> (u32, u32) {
+        self.finished_sets
+            .iter()
+            .fold((0, 0), |(player, ai), &(player_games, ai_games)| {
+                if player_games > ai_games {
+                    (player + 1, ai)
+                } else {
+                    (player, ai + 1)
+                }
+            })
+    }
+
+    fn set_won(&self) -> bool {
+        let (high, low) = if self.player_games > self.ai_games {
+            (self.player_games, self.ai_games)
+        } else {
+            (self.ai_games, self.player_games)
+        };
+        // 7-6 is the tiebreak game deciding the set
+        (high >= GAMES_PER_SET && high - low >= 2) || high == GAMES_PER_SET + 1
+    }
+
+    // standard notation, e.g. "6-3, 4-5"
+    fn notation(&self) -> String {
+        self.finished_sets
+            .iter()
+            .chain(std::iter::once(&(self.player_games, self.ai_games)))
+            .map(|(player, ai)| format!("{player}-{ai}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[derive(Component)]
+struct TennisScoreboard;
+
+pub struct TennisSetsPlugin;
+
+impl Plugin for TennisSetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TennisSets>()
+            .init_resource::<TennisScore>()
+            // a set left unfinished doesn't carry into the next match
+            .add_systems(OnEnter(InMatch), reset_resource::<TennisScore>)
+            .add_systems(Startup, spawn_tennis_scoreboard)
+            // in `Update` so every change to `Score` during the
+            // physics steps, bonuses included, is already in
+            .add_systems(
+                Update,
+                (
+                    update_tennis_score.after(toggle_tennis_sets),
+                    update_tennis_scoreboard.after(update_tennis_score),
+                ),
+            )
+            .add_game_mode(KeyCode::KeyT, "mode_tennis", toggle_tennis_sets);
+    }
+}
+
+fn spawn_tennis_scoreboard(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "0-0",
+            TextStyle {
+                font_size: 30.,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(30.0),
+            left: Val::Px(80.0),
+            ..default()
+        }),
+        Visibility::Hidden,
+        TennisScoreboard,
+    ));
+}
+
+fn toggle_tennis_sets(
+    mut tennis: ResMut<TennisSets>,
+    mut winning_score: ResMut<WinningScore>,
+    mut saved_winning_score: Local<Option<u32>>,
+) {
+    tennis.0 = !tennis.0;
+    info!("Tennis sets: {}", tennis.0);
+
+    // the sets decide the match, so the points can't end it first
+    if tennis.0 {
+        *saved_winning_score = winning_score.0.take();
+    } else {
+        winning_score.0 = saved_winning_score.take();
+    }
+}
+
+fn update_tennis_score(
+    tennis: Res<TennisSets>,
+    mut tennis_score: ResMut<TennisScore>,
+    mut score: ResMut<Score>,
+    mut match_over: EventWriter<MatchOver>,
+) {
+    if !tennis.0 || !score.is_changed() {
+        return;
+    }
+
+    let (high, low) = (score.player.max(score.ai), score.player.min(score.ai));
+    if high < POINTS_PER_GAME || high - low < 2 {
+        return;
+    }
+
+    if score.player > score.ai {
+        tennis_score.player_games += 1;
+    } else {
+        tennis_score.ai_games += 1;
+    }
+    // the points start over every game
+    *score = Score::default();
+
+    if tennis_score.set_won() {
+        let games = (tennis_score.player_games, tennis_score.ai_games);
+        tennis_score.finished_sets.push(games);
+        tennis_score.player_games = 0;
+        tennis_score.ai_games = 0;
+    }
+
+    let (player_sets, ai_sets) = tennis_score.sets_won();
+    if player_sets == SETS_TO_WIN || ai_sets == SETS_TO_WIN {
+        let winner = if player_sets > ai_sets {
+            Scorer::Player
+        } else {
+            Scorer::Ai
+        };
+        info!("{winner:?} wins the match {}", tennis_score.notation());
+        *tennis_score = TennisScore::default();
+        match_over.send(MatchOver(winner));
+    }
+}
+
+fn update_tennis_scoreboard(
+    tennis: Res<TennisSets>,
+    tennis_score: Res<TennisScore>,
+    mut scoreboard: Query<(&mut Text, &mut Visibility), With<TennisScoreboard>>,
+) {
+    let Ok((mut text, mut visibility)) = scoreboard.get_single_mut() else {
+        return;
+    };
+
+    if tennis.is_changed() {
+        *visibility = if tennis.0 {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+    if tennis_score.is_changed() {
+        text.sections[0].value = tennis_score.notation();
+    }
+}
@@ -0,0 +1,74 @@
+// Deterministic keyboard input for headless tests of the game logic
+
+use std::time::Duration;
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::ai::{AiOpponent, AiPlugin};
+use crate::ball::BallPlugin;
+use crate::game_state::GameState;
+use crate::gutter::GutterPlugin;
+use crate::input_recorder::{InputPlayback, InputRecorderPlugin};
+use crate::paddle::PaddlePlugin;
+use crate::score::{Score, ScorePlugin};
+use crate::BASE_TIMESTEP_HZ;
+
+// A windowless game with one physics step per update, built from
+// the same plugins as the real one
+pub(crate) fn headless_app(inputs: Vec<(u64, KeyCode, bool)>) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        InputPlugin,
+        InputRecorderPlugin,
+        BallPlugin,
+        PaddlePlugin,
+        AiPlugin,
+        GutterPlugin,
+        ScorePlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1. / BASE_TIMESTEP_HZ,
+    )))
+    .insert_resource(Time::<Fixed>::from_hz(BASE_TIMESTEP_HZ))
+    .insert_state(GameState::Playing)
+    .insert_resource(InputPlayback { inputs })
+    // normally added by the rendering plugins
+    .init_resource::<Assets<Mesh>>()
+    .init_resource::<Assets<ColorMaterial>>();
+
+    // the paddles are placed against the window's sides
+    app.world_mut().spawn(Window::default());
+
+    app
+}
+
+pub(crate) fn run_frames(app: &mut App, frames: u32) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+#[test]
+fn ball_bounces_back_without_input() {
+    let mut app = headless_app(vec![]);
+    run_frames(&mut app, 150);
+
+    let score = app.world().resource::<Score>();
+    assert_eq!((score.player, score.ai), (0, 0));
+}
+
+#[test]
+fn moving_ai_paddle_away_lets_player_score() {
+    // hold the AI paddle's up key long enough to clear the ball's path
+    let mut app = headless_app(vec![(5, KeyCode::KeyW, true), (35, KeyCode::KeyW, false)]);
+    app.insert_resource(AiOpponent(false));
+    run_frames(&mut app, 150);
+
+    let score = app.world().resource::<Score>();
+    assert_eq!((score.player, score.ai), (1, 0));
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+use crate::localization::{t, Locale};
+
+const TOAST_SECS: f32 = 2.;
+
+// A short message shown at the bottom of the screen,
+// given as a key into the localization table
+#[derive(Event)]
+pub struct Toast(pub &'static str);
+
+#[derive(Component)]
+struct ToastText(Timer);
+
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Toast>()
+            .add_systems(Update, (show_toasts, despawn_toasts));
+    }
+}
+
+fn show_toasts(
+    mut commands: Commands,
+    mut toasts: EventReader<Toast>,
+    existing: Query<Entity, With<ToastText>>,
+    locale: Res<Locale>,
+) {
+    // only the latest toast is shown, it replaces any still on screen
+    let Some(toast) = toasts.read().last() else {
+        return;
+    };
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(40.),
+                width: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ToastText(Timer::from_seconds(TOAST_SECS, TimerMode::Once)))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                t(toast.0, &locale),
+                TextStyle {
+                    font_size: 30.,
+                    color: Color::srgb_u8(255, 200, 50),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn despawn_toasts(
+    mut commands: Commands,
+    mut toasts: Query<(Entity, &mut ToastText)>,
+    time: Res<Time>,
+) {
+    for (entity, mut toast) in &mut toasts {
+        if toast.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,549 @@
+use std::fs;
+
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use bevy::scene::ron;
+use rand::random;
+use serde::{Deserialize, Serialize};
+
+use crate::ai::AiDifficulty;
+use crate::game_state::{despawn_screen, GameOverPhase, GameState, InMatch, MatchResult};
+use crate::localization::{t, Locale};
+use crate::profile::PlayerProfile;
+use crate::score::{Scorer, WinningScore};
+
+const BEATEN_PATH: &str = "tournament.ron";
+const GOLD: Color = Color::srgb(1., 0.84, 0.);
+const PORTRAIT_SIZE: f32 = 24.;
+const TROPHY_GROW_SECS: f32 = 1.;
+const TROPHY_SECS: f32 = 4.;
+
+// Who can be in the bracket: the player, or one of the AI personas
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+enum Persona {
+    Player,
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Persona {
+    fn name(self, profile: &PlayerProfile) -> String {
+        match self {
+            Persona::Player => profile.name.clone(),
+            Persona::Easy => "Pixel".to_string(),
+            Persona::Medium => "Volley".to_string(),
+            Persona::Hard => "Smash".to_string(),
+        }
+    }
+
+    fn color(self, profile: &PlayerProfile) -> Color {
+        match self {
+            Persona::Player => profile.paddle_color,
+            Persona::Easy => Color::srgb_u8(90, 200, 90),
+            Persona::Medium => Color::srgb_u8(230, 170, 50),
+            Persona::Hard => Color::srgb_u8(220, 60, 60),
+        }
+    }
+
+    fn difficulty(self) -> Option<AiDifficulty> {
+        match self {
+            Persona::Player => None,
+            Persona::Easy => Some(AiDifficulty::Easy),
+            Persona::Medium => Some(AiDifficulty::Medium),
+            Persona::Hard => Some(AiDifficulty::Hard),
+        }
+    }
+}
+
+// Two entrants, and the score once they've played, in the same order
+struct Matchup {
+    sides: [Persona; 2],
+    score: Option<[u32; 2]>,
+}
+
+impl Matchup {
+    fn new(sides: [Persona; 2]) -> Self {
+        Matchup { sides, score: None }
+    }
+
+    fn winner(&self) -> Option<Persona> {
+        self.score
+            .map(|[a, b]| if a > b { self.sides[0] } else { self.sides[1] })
+    }
+
+    fn has_player(&self) -> bool {
+        self.sides.contains(&Persona::Player)
+    }
+}
+
+// A four entrant, single elimination bracket: the two round one
+// matchups, then the final between their winners. Empty while no
+// tournament is on.
+#[derive(Resource, Default)]
+struct Tournament(Vec<Matchup>);
+
+impl Tournament {
+    fn start() -> Self {
+        Tournament(vec![
+            Matchup::new([Persona::Player, Persona::Easy]),
+            Matchup::new([Persona::Hard, Persona::Medium]),
+        ])
+    }
+
+    // The AI the player meets next, if the player is still in it
+    fn next_opponent(&self) -> Option<Persona> {
+        self.0
+            .iter()
+            .find(|matchup| matchup.score.is_none() && matchup.has_player())
+            .map(|matchup| matchup.sides[1])
+    }
+
+    fn champion(&self) -> Option<Persona> {
+        self.0.get(2).and_then(Matchup::winner)
+    }
+
+    // Plays out whatever the player isn't in, and draws up the final
+    // once both of round one's winners are known
+    fn advance(&mut self, winning_score: u32) {
+        for matchup in &mut self.0 {
+            if matchup.score.is_none() && !matchup.has_player() {
+                matchup.score = Some(simulate(matchup.sides, winning_score));
+            }
+        }
+        if self.0.len() == 2 {
+            if let (Some(a), Some(b)) = (self.0[0].winner(), self.0[1].winner()) {
+                self.0.push(Matchup::new([a, b]));
+                self.advance(winning_score);
+            }
+        }
+    }
+}
+
+// Two AIs play off screen, the quicker one more likely to win
+fn simulate(sides: [Persona; 2], winning_score: u32) -> [u32; 2] {
+    let speed = |persona: Persona| persona.difficulty().unwrap_or_default().max_speed();
+    let (a, b) = (speed(sides[0]), speed(sides[1]));
+    let losing_score = (random::<f32>() * winning_score as f32) as u32;
+    if random::<f32>() < a / (a + b) {
+        [winning_score, losing_score]
+    } else {
+        [losing_score, winning_score]
+    }
+}
+
+// Every AI persona the player has ever knocked out, kept between sessions
+#[derive(Resource, Default)]
+struct BeatenPersonas(Vec<Persona>);
+
+fn load_beaten() -> Vec<Persona> {
+    match fs::read_to_string(BEATEN_PATH) {
+        Ok(contents) => ron::from_str(&contents).unwrap_or_else(|err| {
+            warn!("Failed to parse {BEATEN_PATH}, starting afresh: {err}");
+            Vec::new()
+        }),
+        // no tournament played yet
+        Err(_) => Vec::new(),
+    }
+}
+
+fn save_beaten(beaten: &[Persona]) {
+    match ron::to_string(beaten) {
+        Ok(serialized) => {
+            if let Err(err) = fs::write(BEATEN_PATH, serialized) {
+                error!("Failed to write {BEATEN_PATH}: {err}");
+            }
+        }
+        Err(err) => error!("Failed to serialize beaten personas: {err}"),
+    }
+}
+
+#[derive(Component)]
+struct BracketScreen;
+
+// The winner's moment, with a trophy growing in, before it
+// goes back to the game over screen
+#[derive(Component)]
+struct TrophyCutscene {
+    age: f32,
+}
+
+// A piece of the trophy, at its full size
+#[derive(Component)]
+struct TrophyPart(Vec2);
+
+pub struct TournamentPlugin;
+
+impl Plugin for TournamentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Tournament>()
+            .insert_resource(BeatenPersonas(load_beaten()))
+            .add_systems(
+                OnEnter(GameOverPhase::Results),
+                (record_tournament_match, show_tournament)
+                    .chain()
+                    .run_if(tournament_on),
+            )
+            .add_systems(
+                OnEnter(InMatch),
+                (
+                    despawn_screen::<BracketScreen>,
+                    despawn_screen::<TrophyCutscene>,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_tournament
+                        .run_if(in_state(GameState::Menu))
+                        .run_if(input_just_pressed(KeyCode::KeyF)),
+                    play_trophy_cutscene,
+                ),
+            );
+    }
+}
+
+fn tournament_on(tournament: Res<Tournament>) -> bool {
+    !tournament.0.is_empty()
+}
+
+// Enters the tournament from the menu, or backs out of it before
+// the first match
+fn toggle_tournament(
+    mut commands: Commands,
+    mut tournament: ResMut<Tournament>,
+    mut difficulty: ResMut<AiDifficulty>,
+    screens: Query<Entity, With<BracketScreen>>,
+    beaten: Res<BeatenPersonas>,
+    profile: Res<PlayerProfile>,
+    locale: Res<Locale>,
+) {
+    for entity in &screens {
+        commands.entity(entity).despawn_recursive();
+    }
+    if tournament.0.is_empty() {
+        *tournament = Tournament::start();
+        if let Some(opponent) = tournament.next_opponent() {
+            *difficulty = opponent.difficulty().unwrap_or_default();
+        }
+        spawn_bracket(&mut commands, &tournament, &beaten, &profile, &locale);
+    } else {
+        *tournament = Tournament::default();
+        *difficulty = AiDifficulty::default();
+    }
+}
+
+fn record_tournament_match(
+    result: Option<Res<MatchResult>>,
+    mut tournament: ResMut<Tournament>,
+    mut beaten: ResMut<BeatenPersonas>,
+    winning_score: Res<WinningScore>,
+) {
+    let Some(result) = result else {
+        return;
+    };
+    let Some(matchup) = tournament
+        .0
+        .iter_mut()
+        .find(|matchup| matchup.score.is_none() && matchup.has_player())
+    else {
+        return;
+    };
+
+    // the player is always the first side of their matchups
+    matchup.score = Some([result.player, result.ai]);
+    let opponent = matchup.sides[1];
+    if matches!(result.winner, Scorer::Player) && !beaten.0.contains(&opponent) {
+        beaten.0.push(opponent);
+        save_beaten(&beaten.0);
+    }
+    // the AIs still need a result when matches are set to go on forever
+    tournament.advance(winning_score.0.unwrap_or(11));
+}
+
+// The bracket between matches, or the trophy once there's a champion
+fn show_tournament(
+    mut commands: Commands,
+    mut tournament: ResMut<Tournament>,
+    mut difficulty: ResMut<AiDifficulty>,
+    beaten: Res<BeatenPersonas>,
+    profile: Res<PlayerProfile>,
+    locale: Res<Locale>,
+) {
+    if let Some(champion) = tournament.champion() {
+        spawn_trophy_cutscene(&mut commands, champion, &profile, &locale);
+        // over, so the next match is an ordinary one
+        *tournament = Tournament::default();
+        *difficulty = AiDifficulty::default();
+    } else if let Some(opponent) = tournament.next_opponent() {
+        *difficulty = opponent.difficulty().unwrap_or_default();
+        spawn_bracket(&mut commands, &tournament, &beaten, &profile, &locale);
+    }
+}
+
+fn text(value: impl Into<String>, font_size: f32, color: Color) -> TextBundle {
+    TextBundle::from_section(
+        value,
+        TextStyle {
+            font_size,
+            color,
+            ..default()
+        },
+    )
+}
+
+// A coloured square for a face, with the name beside it
+fn spawn_portrait(
+    parent: &mut ChildBuilder,
+    persona: Persona,
+    label: String,
+    profile: &PlayerProfile,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(PORTRAIT_SIZE),
+                    height: Val::Px(PORTRAIT_SIZE),
+                    ..default()
+                },
+                background_color: persona.color(profile).into(),
+                ..default()
+            });
+            row.spawn(text(label, 22., Color::WHITE));
+        });
+}
+
+fn spawn_bracket(
+    commands: &mut Commands,
+    tournament: &Tournament,
+    beaten: &BeatenPersonas,
+    profile: &PlayerProfile,
+    locale: &Locale,
+) {
+    let matchup_box = |column: &mut ChildBuilder, matchup: &Matchup| {
+        column
+            .spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(10.)),
+                    row_gap: Val::Px(6.),
+                    min_width: Val::Px(220.),
+                    ..default()
+                },
+                background_color: Color::srgb(0.15, 0.15, 0.2).into(),
+                ..default()
+            })
+            .with_children(|matchup_box| {
+                for (side, persona) in matchup.sides.into_iter().enumerate() {
+                    let mut label = persona.name(profile);
+                    if let Some(score) = matchup.score {
+                        label = format!("{label}  {}", score[side]);
+                    }
+                    if beaten.0.contains(&persona) {
+                        label = format!("{label}  ({})", t("beaten", locale));
+                    }
+                    spawn_portrait(matchup_box, persona, label, profile);
+                }
+            });
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(30.),
+                    ..default()
+                },
+                background_color: Color::BLACK.into(),
+                // over the menu and game over screens
+                z_index: ZIndex::Global(95),
+                ..default()
+            },
+            BracketScreen,
+        ))
+        .with_children(|screen| {
+            screen.spawn(text(t("tournament", locale), 60., GOLD));
+            screen
+                .spawn(NodeBundle {
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(60.),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|rounds| {
+                    rounds
+                        .spawn(NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(40.),
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|column| {
+                            column.spawn(text(t("round_one", locale), 20., GOLD));
+                            for matchup in &tournament.0[..2] {
+                                matchup_box(column, matchup);
+                            }
+                        });
+                    rounds
+                        .spawn(NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(40.),
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|column| {
+                            column.spawn(text(t("final", locale), 20., GOLD));
+                            match tournament.0.get(2) {
+                                Some(matchup) => matchup_box(column, matchup),
+                                None => {
+                                    column.spawn(text("?", 22., Color::WHITE));
+                                }
+                            }
+                        });
+                });
+            screen.spawn(text(t("tournament_hint", locale), 24., Color::WHITE));
+        });
+}
+
+fn spawn_trophy_cutscene(
+    commands: &mut Commands,
+    champion: Persona,
+    profile: &PlayerProfile,
+    locale: &Locale,
+) {
+    let trophy_part = |trophy: &mut ChildBuilder, size: Vec2| {
+        trophy.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(0.),
+                    height: Val::Px(0.),
+                    ..default()
+                },
+                background_color: GOLD.into(),
+                ..default()
+            },
+            TrophyPart(size),
+        ));
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(24.),
+                    ..default()
+                },
+                background_color: Color::BLACK.into(),
+                z_index: ZIndex::Global(95),
+                ..default()
+            },
+            TrophyCutscene { age: 0. },
+        ))
+        .with_children(|screen| {
+            // the cup, its stem and its base
+            screen
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        height: Val::Px(160.),
+                        justify_content: JustifyContent::End,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|trophy| {
+                    trophy_part(trophy, Vec2::new(100., 80.));
+                    trophy_part(trophy, Vec2::new(20., 40.));
+                    trophy_part(trophy, Vec2::new(80., 16.));
+                });
+            spawn_portrait(screen, champion, champion.name(profile), profile);
+            screen.spawn(text(
+                t("tournament_champion", locale).replace("{name}", &champion.name(profile)),
+                40.,
+                GOLD,
+            ));
+        });
+}
+
+fn play_trophy_cutscene(
+    mut commands: Commands,
+    mut cutscenes: Query<(Entity, &mut TrophyCutscene)>,
+    mut parts: Query<(&TrophyPart, &mut Style)>,
+    time: Res<Time>,
+) {
+    for (entity, mut cutscene) in &mut cutscenes {
+        cutscene.age += time.delta_seconds();
+        if cutscene.age >= TROPHY_SECS {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let grown = (cutscene.age / TROPHY_GROW_SECS).min(1.);
+        for (part, mut style) in &mut parts {
+            style.width = Val::Px(part.0.x * grown);
+            style.height = Val::Px(part.0.y * grown);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_final_is_drawn_up_from_round_one() {
+        let mut tournament = Tournament::start();
+        assert_eq!(tournament.next_opponent(), Some(Persona::Easy));
+
+        tournament.0[0].score = Some([11, 4]);
+        tournament.advance(11);
+
+        // the AI matchup was played out alongside
+        assert!(tournament.0[1].score.is_some());
+        let final_matchup = &tournament.0[2];
+        assert_eq!(final_matchup.sides[0], Persona::Player);
+        assert_eq!(tournament.next_opponent(), Some(final_matchup.sides[1]));
+        assert_eq!(tournament.champion(), None);
+    }
+
+    #[test]
+    fn a_knocked_out_player_still_gets_a_champion() {
+        let mut tournament = Tournament::start();
+        tournament.0[0].score = Some([3, 11]);
+        tournament.advance(11);
+
+        assert_eq!(tournament.next_opponent(), None);
+        assert!(matches!(
+            tournament.champion(),
+            Some(Persona::Easy | Persona::Medium | Persona::Hard)
+        ));
+    }
+}
@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+
+use crate::game_state::InMatch;
+use crate::{Ball, HitCount, Position, BALL_RADIUS};
+
+const BASE_TRAIL_LENGTH: u32 = 5;
+const TRAIL_GROWTH_PER_HIT: u32 = 2;
+const MAX_TRAIL_LENGTH: u32 = 30;
+
+// How many particles (physics steps) a ball's trail lasts
+#[derive(Resource)]
+struct MaxTrailLength(u32);
+
+impl Default for MaxTrailLength {
+    fn default() -> Self {
+        MaxTrailLength(BASE_TRAIL_LENGTH)
+    }
+}
+
+// Trail particles are made once up front and recycled, rather than
+// spawning and despawning one per ball every physics step
+const TRAIL_POOL_SIZE: usize = 100;
+
+#[derive(Resource, Default)]
+struct ParticlePool {
+    available: Vec<Entity>,
+    in_use: Vec<Entity>,
+}
+
+#[derive(Component)]
+struct TrailParticle {
+    age: u32,
+}
+
+pub struct BallTrailPlugin;
+
+impl Plugin for BallTrailPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MaxTrailLength>()
+            .init_resource::<ParticlePool>()
+            .add_systems(Startup, spawn_particle_pool)
+            .add_systems(OnExit(InMatch), return_trail_particles)
+            // one particle per physics step keeps the spacing even
+            .add_systems(
+                FixedUpdate,
+                (
+                    trail_length,
+                    age_trail_particles.after(trail_length),
+                    spawn_ball_trail.after(age_trail_particles),
+                ),
+            );
+    }
+}
+
+fn spawn_particle_pool(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut pool: ResMut<ParticlePool>,
+) {
+    let mesh = meshes.add(Circle::new(BALL_RADIUS));
+    let material = materials.add(ColorMaterial::from_color(Color::srgba_u8(
+        50, 100, 200, 100,
+    )));
+
+    for _ in 0..TRAIL_POOL_SIZE {
+        let entity = commands
+            .spawn((
+                TrailParticle { age: 0 },
+                MaterialMesh2dBundle {
+                    mesh: mesh.clone().into(),
+                    material: material.clone(),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+            ))
+            .id();
+        pool.available.push(entity);
+    }
+}
+
+// the longer the rally, the longer the comet tail
+fn trail_length(balls: Query<&HitCount, With<Ball>>, mut max_length: ResMut<MaxTrailLength>) {
+    let hit_count = balls.iter().map(|hits| hits.0).max().unwrap_or(0);
+    let length = (BASE_TRAIL_LENGTH + hit_count * TRAIL_GROWTH_PER_HIT).min(MAX_TRAIL_LENGTH);
+    if max_length.0 != length {
+        max_length.0 = length;
+    }
+}
+
+fn age_trail_particles(
+    mut pool: ResMut<ParticlePool>,
+    mut particles: Query<(&mut TrailParticle, &mut Transform, &mut Visibility)>,
+    max_length: Res<MaxTrailLength>,
+) {
+    let ParticlePool { available, in_use } = &mut *pool;
+    in_use.retain(|&entity| {
+        let Ok((mut particle, mut transform, mut visibility)) = particles.get_mut(entity) else {
+            return false;
+        };
+
+        particle.age += 1;
+        if particle.age >= max_length.0 {
+            *visibility = Visibility::Hidden;
+            available.push(entity);
+            false
+        } else {
+            // older particles shrink away towards the end of the tail
+            let scale = 1. - particle.age as f32 / max_length.0 as f32;
+            transform.scale = Vec3::splat(scale);
+            true
+        }
+    });
+}
+
+// the balls they trailed are gone with the match
+fn return_trail_particles(
+    mut pool: ResMut<ParticlePool>,
+    mut particles: Query<&mut Visibility, With<TrailParticle>>,
+) {
+    let ParticlePool { available, in_use } = &mut *pool;
+    for entity in in_use.drain(..) {
+        if let Ok(mut visibility) = particles.get_mut(entity) {
+            *visibility = Visibility::Hidden;
+        }
+        available.push(entity);
+    }
+}
+
+fn spawn_ball_trail(
+    mut pool: ResMut<ParticlePool>,
+    balls: Query<&Position, With<Ball>>,
+    mut particles: Query<(&mut TrailParticle, &mut Transform, &mut Visibility)>,
+) {
+    for position in &balls {
+        // with enough balls the tails just get patchy
+        let Some(entity) = pool.available.pop() else {
+            return;
+        };
+        let Ok((mut particle, mut transform, mut visibility)) = particles.get_mut(entity) else {
+            continue;
+        };
+
+        particle.age = 0;
+        // just behind the ball
+        *transform = Transform::from_translation(position.0.extend(-0.1));
+        *visibility = Visibility::Visible;
+        pool.in_use.push(entity);
+    }
+}
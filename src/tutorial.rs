@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+
+use crate::game_state::GameState;
+use crate::key_bindings::KeyBindings;
+use crate::localization::{t, Locale};
+use crate::{settings, BallFrozen, Scored, Scorer};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TutorialStep {
+    Welcome,
+    LearnMove,
+    LearnServe,
+    LearnScore,
+    Complete,
+}
+
+impl TutorialStep {
+    fn text_key(self) -> &'static str {
+        match self {
+            TutorialStep::Welcome => "tutorial_welcome",
+            TutorialStep::LearnMove => "tutorial_move",
+            TutorialStep::LearnServe => "tutorial_serve",
+            TutorialStep::LearnScore => "tutorial_score",
+            TutorialStep::Complete => "tutorial_complete",
+        }
+    }
+
+    // the ball waits at centre until the player has learnt to serve
+    fn holds_ball(self) -> bool {
+        matches!(
+            self,
+            TutorialStep::Welcome | TutorialStep::LearnMove | TutorialStep::LearnServe
+        )
+    }
+}
+
+#[derive(Resource)]
+struct TutorialState {
+    step: TutorialStep,
+    active: bool,
+}
+
+#[derive(Component)]
+struct TutorialText;
+
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        // only shown until it has been completed once
+        let active = !settings::load().tutorial_completed;
+
+        app.insert_resource(TutorialState {
+            step: TutorialStep::Welcome,
+            active,
+        })
+        .insert_resource(BallFrozen(active))
+        .add_systems(Startup, spawn_tutorial_text)
+        .add_systems(
+            Update,
+            (
+                // not while the Space that starts the match is still down
+                advance_tutorial.run_if(in_state(GameState::Playing)),
+                update_tutorial_text.after(advance_tutorial),
+            ),
+        );
+    }
+}
+
+fn spawn_tutorial_text(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 28.,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(20.),
+            left: Val::Percent(20.),
+            ..default()
+        }),
+        TutorialText,
+    ));
+}
+
+fn advance_tutorial(
+    mut tutorial: ResMut<TutorialState>,
+    mut ball_frozen: ResMut<BallFrozen>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut scored_events: EventReader<Scored>,
+) {
+    if !tutorial.active {
+        scored_events.clear();
+        return;
+    }
+
+    let player_scored = scored_events
+        .read()
+        .any(|event| matches!(event.0, Scorer::Player));
+
+    let next = match tutorial.step {
+        TutorialStep::Welcome if keyboard_input.just_pressed(KeyCode::Space) => {
+            Some(TutorialStep::LearnMove)
+        }
+        TutorialStep::LearnMove
+            if keyboard_input.any_pressed([bindings.player_up, bindings.player_down]) =>
+        {
+            Some(TutorialStep::LearnServe)
+        }
+        TutorialStep::LearnServe if keyboard_input.just_pressed(bindings.serve) => {
+            Some(TutorialStep::LearnScore)
+        }
+        TutorialStep::LearnScore if player_scored => Some(TutorialStep::Complete),
+        TutorialStep::Complete if keyboard_input.just_pressed(KeyCode::Space) => {
+            tutorial.active = false;
+            settings::update(|settings| settings.tutorial_completed = true);
+            info!("Tutorial complete");
+            None
+        }
+        _ => None,
+    };
+
+    if let Some(step) = next {
+        tutorial.step = step;
+    }
+
+    let holds_ball = tutorial.active && tutorial.step.holds_ball();
+    if ball_frozen.0 != holds_ball {
+        ball_frozen.0 = holds_ball;
+    }
+}
+
+fn update_tutorial_text(
+    tutorial: Res<TutorialState>,
+    locale: Res<Locale>,
+    mut text: Query<&mut Text, With<TutorialText>>,
+) {
+    if tutorial.is_changed() || locale.is_changed() {
+        if let Ok(mut text) = text.get_single_mut() {
+            text.sections[0].value = if tutorial.active {
+                t(tutorial.step.text_key(), &locale).to_string()
+            } else {
+                String::new()
+            };
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::{Ai, Ball, BallAssets, BallBundle, Paddle, Player, Position, Score, Scored, Velocity};
+
+const MAX_POINT_HISTORY: usize = 10;
+// how many frames before the score the restored snapshot is taken,
+// so the play leading up to the point can be watched again
+const SNAPSHOT_LEAD_FRAMES: usize = 60;
+
+#[derive(Clone)]
+struct WorldSnapshot {
+    // (position, velocity) of every ball in play
+    balls: Vec<(Vec2, Vec2)>,
+    player_paddle: Vec2,
+    ai_paddle: Vec2,
+    score: (u32, u32),
+}
+
+#[derive(Resource, Default)]
+struct PointHistory {
+    points: VecDeque<WorldSnapshot>,
+    recent_frames: VecDeque<WorldSnapshot>,
+}
+
+pub struct UndoPlugin;
+
+impl Plugin for UndoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PointHistory>().add_systems(
+            Update,
+            (
+                record_point_history,
+                undo_last_point.after(record_point_history),
+            ),
+        );
+    }
+}
+
+fn record_point_history(
+    mut history: ResMut<PointHistory>,
+    balls: Query<(&Position, &Velocity), With<Ball>>,
+    player_paddle: Query<&Position, With<Player>>,
+    ai_paddle: Query<&Position, With<Ai>>,
+    score: Res<Score>,
+    mut events: EventReader<Scored>,
+) {
+    // keep the snapshot from before the point was scored
+    if events.read().last().is_some() {
+        if let Some(snapshot) = history.recent_frames.front().cloned() {
+            history.points.push_back(snapshot);
+            if history.points.len() > MAX_POINT_HISTORY {
+                history.points.pop_front();
+            }
+        }
+        history.recent_frames.clear();
+    }
+
+    if let (Ok(player_paddle), Ok(ai_paddle)) = (player_paddle.get_single(), ai_paddle.get_single())
+    {
+        history.recent_frames.push_back(WorldSnapshot {
+            balls: balls
+                .iter()
+                .map(|(position, velocity)| (position.0, velocity.0))
+                .collect(),
+            player_paddle: player_paddle.0,
+            ai_paddle: ai_paddle.0,
+            score: (score.player, score.ai),
+        });
+        if history.recent_frames.len() > SNAPSHOT_LEAD_FRAMES {
+            history.recent_frames.pop_front();
+        }
+    }
+}
+
+fn undo_last_point(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut history: ResMut<PointHistory>,
+    balls: Query<Entity, With<Ball>>,
+    mut paddles: Query<(&mut Position, Has<Player>), With<Paddle>>,
+    mut score: ResMut<Score>,
+    ball_assets: Res<BallAssets>,
+) {
+    let ctrl = keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if !(ctrl && keyboard_input.just_pressed(KeyCode::KeyZ)) {
+        return;
+    }
+
+    let Some(snapshot) = history.points.pop_back() else {
+        info!("No points to undo");
+        return;
+    };
+
+    for entity in &balls {
+        commands.entity(entity).despawn();
+    }
+    for (position, velocity) in snapshot.balls {
+        let mut ball = BallBundle::new(velocity.x, velocity.y);
+        ball.position.0 = position;
+        commands.spawn((ball, ball_assets.bundle()));
+    }
+
+    for (mut position, is_player) in &mut paddles {
+        position.0 = if is_player {
+            snapshot.player_paddle
+        } else {
+            snapshot.ai_paddle
+        };
+    }
+
+    (score.player, score.ai) = snapshot.score;
+    history.recent_frames.clear();
+
+    info!(
+        "Undid last point, score back to {} - {}",
+        score.player, score.ai
+    );
+}
@@ -0,0 +1,172 @@
+use bevy::prelude::*;
+
+use crate::game_state::{GameOverPhase, MatchResult};
+use crate::localization::{t, Locale};
+use crate::score::Scorer;
+use crate::{Ai, Paddle, Player, Position, PADDLE_HEIGHT};
+
+const LAP_SECS: f32 = 3.;
+const WINNER_TEXT_GROW_SECS: f32 = 0.5;
+
+// The way round the field the winning paddle takes, at a steady speed,
+// ending back where it started
+#[derive(Component)]
+struct VictoryPath(Vec<Vec2>);
+
+// Seconds into the lap
+#[derive(Resource, Default)]
+struct LapTime(f32);
+
+#[derive(Component)]
+struct WinnerText;
+
+pub struct VictoryLapPlugin;
+
+impl Plugin for VictoryLapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LapTime>()
+            .add_systems(OnEnter(GameOverPhase::VictoryLap), start_victory_lap)
+            .add_systems(OnExit(GameOverPhase::VictoryLap), end_victory_lap)
+            .add_systems(
+                Update,
+                (animate_victory, grow_winner_text).run_if(in_state(GameOverPhase::VictoryLap)),
+            );
+    }
+}
+
+fn start_victory_lap(
+    mut commands: Commands,
+    result: Option<Res<MatchResult>>,
+    paddles: Query<(Entity, &Position, Has<Player>, Has<Ai>), With<Paddle>>,
+    window: Query<&Window>,
+    locale: Res<Locale>,
+    mut lap_time: ResMut<LapTime>,
+) {
+    *lap_time = LapTime::default();
+    let (Some(result), Ok(window)) = (result, window.get_single()) else {
+        return;
+    };
+
+    // clear of the walls, so the whole paddle stays in view
+    let top = window.resolution.height() / 2. - PADDLE_HEIGHT;
+    for (entity, position, is_player, is_ai) in &paddles {
+        let won = match result.winner {
+            Scorer::Player => is_player,
+            Scorer::Ai => is_ai,
+        };
+        if !won {
+            continue;
+        }
+        let start = position.0;
+        // up its own side, across the top, down the loser's side,
+        // back along the bottom and home
+        commands.entity(entity).insert(VictoryPath(vec![
+            start,
+            Vec2::new(start.x, top),
+            Vec2::new(-start.x, top),
+            Vec2::new(-start.x, -top),
+            Vec2::new(start.x, -top),
+            start,
+        ]));
+    }
+
+    commands.spawn((
+        WinnerText,
+        StateScoped(GameOverPhase::VictoryLap),
+        Text2dBundle {
+            text: Text::from_section(
+                t("winner", &locale),
+                TextStyle {
+                    font_size: 80.,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ),
+            // over the field and the confetti, growing from nothing
+            transform: Transform::from_xyz(0., 0., 10.).with_scale(Vec3::ZERO),
+            ..default()
+        },
+    ));
+}
+
+// Moves the winner straight to its place on the path, nothing
+// else gets a say in where it is until the lap is over
+fn animate_victory(
+    mut paddles: Query<(&VictoryPath, &mut Position)>,
+    mut lap_time: ResMut<LapTime>,
+    time: Res<Time>,
+    mut next_phase: ResMut<NextState<GameOverPhase>>,
+) {
+    lap_time.0 += time.delta_seconds();
+    let progress = (lap_time.0 / LAP_SECS).min(1.);
+    for (path, mut position) in &mut paddles {
+        position.0 = point_along(&path.0, progress);
+    }
+    if progress >= 1. {
+        next_phase.set(GameOverPhase::Results);
+    }
+}
+
+fn grow_winner_text(mut texts: Query<&mut Transform, With<WinnerText>>, lap_time: Res<LapTime>) {
+    let scale = (lap_time.0 / WINNER_TEXT_GROW_SECS).min(1.);
+    for mut transform in &mut texts {
+        transform.scale = Vec3::splat(scale);
+    }
+}
+
+// The paddles go back where the match left them, however the lap ended
+fn end_victory_lap(
+    mut commands: Commands,
+    mut paddles: Query<(Entity, &VictoryPath, &mut Position)>,
+) {
+    for (entity, path, mut position) in &mut paddles {
+        if let Some(start) = path.0.first() {
+            position.0 = *start;
+        }
+        commands.entity(entity).remove::<VictoryPath>();
+    }
+}
+
+// Where along `keyframes` a steady walk is at `progress`, from 0 at the
+// first keyframe to 1 at the last
+fn point_along(keyframes: &[Vec2], progress: f32) -> Vec2 {
+    let total: f32 = keyframes
+        .windows(2)
+        .map(|leg| leg[0].distance(leg[1]))
+        .sum();
+    let mut left = total * progress;
+    for leg in keyframes.windows(2) {
+        let length = leg[0].distance(leg[1]);
+        if left <= length && length > 0. {
+            return leg[0].lerp(leg[1], left / length);
+        }
+        left -= length;
+    }
+    keyframes.last().copied().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQUARE: [Vec2; 5] = [
+        Vec2::new(0., 0.),
+        Vec2::new(0., 10.),
+        Vec2::new(10., 10.),
+        Vec2::new(10., 0.),
+        Vec2::new(0., 0.),
+    ];
+
+    #[test]
+    fn the_lap_starts_and_ends_at_home() {
+        assert_eq!(point_along(&SQUARE, 0.), Vec2::ZERO);
+        assert_eq!(point_along(&SQUARE, 1.), Vec2::ZERO);
+    }
+
+    #[test]
+    fn the_lap_goes_at_a_steady_speed() {
+        assert_eq!(point_along(&SQUARE, 0.125), Vec2::new(0., 5.));
+        assert_eq!(point_along(&SQUARE, 0.5), Vec2::new(10., 10.));
+        assert_eq!(point_along(&SQUARE, 0.625), Vec2::new(10., 5.));
+    }
+}
@@ -0,0 +1,128 @@
+use std::io::Write;
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::ScreenshotManager;
+use bevy::window::PrimaryWindow;
+
+const RECORD_FPS: u32 = 60;
+
+// Where `--record-video` asked for the video to go
+#[derive(Resource)]
+struct RecordVideoPath(String);
+
+// An ffmpeg process taking raw RGBA frames on stdin
+#[derive(Resource)]
+struct VideoRecorder {
+    ffmpeg: Child,
+    // shared with the screenshot callbacks, which run on the render side,
+    // and taken on exit so ffmpeg sees the end of its input
+    stdin: Arc<Mutex<Option<ChildStdin>>>,
+    size: UVec2,
+}
+
+pub struct VideoCapturePlugin;
+
+impl Plugin for VideoCapturePlugin {
+    fn build(&self, app: &mut App) {
+        let args: Vec<String> = std::env::args().collect();
+        let path = args
+            .iter()
+            .position(|arg| arg == "--record-video")
+            .and_then(|index| args.get(index + 1));
+        let Some(path) = path else {
+            return;
+        };
+
+        app.insert_resource(RecordVideoPath(path.clone()))
+            .add_systems(Startup, start_recording)
+            .add_systems(
+                Update,
+                capture_frame.run_if(resource_exists::<VideoRecorder>),
+            )
+            .add_systems(
+                Last,
+                finish_recording
+                    .run_if(resource_exists::<VideoRecorder>)
+                    .run_if(on_event::<AppExit>()),
+            );
+    }
+}
+
+fn start_recording(
+    mut commands: Commands,
+    path: Res<RecordVideoPath>,
+    window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    let size = window.physical_size();
+
+    let spawned = Command::new("ffmpeg")
+        .args(["-y", "-f", "rawvideo", "-pix_fmt", "rgba"])
+        .args(["-video_size", &format!("{}x{}", size.x, size.y)])
+        .args(["-framerate", &RECORD_FPS.to_string(), "-i", "-"])
+        .args(["-c:v", "libx264", "-pix_fmt", "yuv420p", &path.0])
+        .stdin(Stdio::piped())
+        .spawn();
+    match spawned {
+        Ok(mut ffmpeg) => {
+            info!("Recording video to {}", path.0);
+            let stdin = Arc::new(Mutex::new(ffmpeg.stdin.take()));
+            commands.insert_resource(VideoRecorder {
+                ffmpeg,
+                stdin,
+                size,
+            });
+        }
+        Err(err) => error!("Failed to start ffmpeg: {err}"),
+    }
+}
+
+fn capture_frame(
+    recorder: Res<VideoRecorder>,
+    mut screenshot_manager: ResMut<ScreenshotManager>,
+    window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+
+    let stdin = recorder.stdin.clone();
+    let size = recorder.size;
+    // fails if the score screenshot got this frame first, which
+    // only costs the video a single frame
+    let _ = screenshot_manager.take_screenshot(window, move |image| {
+        // ffmpeg was told the size up front, so frames from
+        // a resized window can't go in
+        if image.size() != size {
+            return;
+        }
+        let Ok(image) = image.try_into_dynamic() else {
+            return;
+        };
+        let Ok(mut stdin) = stdin.lock() else {
+            return;
+        };
+        if let Some(pipe) = stdin.as_mut() {
+            if let Err(err) = pipe.write_all(&image.to_rgba8().into_raw()) {
+                error!("Failed to write video frame: {err}");
+                *stdin = None;
+            }
+        }
+    });
+}
+
+fn finish_recording(mut recorder: ResMut<VideoRecorder>) {
+    // closing stdin lets ffmpeg finish writing the file
+    if let Ok(mut stdin) = recorder.stdin.lock() {
+        stdin.take();
+    }
+    match recorder.ffmpeg.wait() {
+        Ok(status) if status.success() => info!("Finished recording video"),
+        Ok(status) => warn!("ffmpeg exited with {status}"),
+        Err(err) => error!("Failed to wait for ffmpeg: {err}"),
+    }
+}
@@ -0,0 +1,330 @@
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+
+use crate::game_state::AddGameMode;
+use crate::slow_motion::NearMiss;
+use crate::{BallFrozen, PaddleHit, Scored, Scorer};
+
+const CHARS_PER_SEC: f32 = 40.;
+// counted from when the line has finished typing out
+const DIALOG_SECS: f32 = 2.5;
+// same as the narrator, a rally this long deserves a mention
+const LONG_RALLY_HITS: u32 = 10;
+const PORTRAIT_SIZE: f32 = 64.;
+
+// Game variant where two characters talk through the match in a
+// dialog box along the bottom of the screen
+#[derive(Resource, Default)]
+struct VisualNovelMode(bool);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Persona {
+    Coach,
+    Commentator,
+}
+
+impl Persona {
+    fn name(self) -> &'static str {
+        match self {
+            Persona::Coach => "Coach",
+            Persona::Commentator => "Commentator",
+        }
+    }
+
+    // the default font has no emoji, so faces are drawn in plain text
+    fn face(self) -> &'static str {
+        match self {
+            Persona::Coach => "-_-",
+            Persona::Commentator => "^o^",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Persona::Coach => Color::srgb_u8(90, 170, 90),
+            Persona::Commentator => Color::srgb_u8(240, 200, 70),
+        }
+    }
+}
+
+// Lines to pick from for each moment, with who says them
+#[derive(Resource)]
+struct DialogLines {
+    serve: Vec<&'static str>,
+    long_rally: Vec<&'static str>,
+    player_scored: Vec<&'static str>,
+    ai_scored: Vec<&'static str>,
+    near_miss: Vec<&'static str>,
+}
+
+impl Default for DialogLines {
+    fn default() -> Self {
+        DialogLines {
+            serve: vec![
+                "Eyes on the ball. Knees bent. Go!",
+                "Nice and steady, just like we practised.",
+                "Don't overthink it, just play your game.",
+            ],
+            long_rally: vec![
+                "Neither of them is giving an inch here!",
+                "This rally just keeps going, folks!",
+                "Back and forth, back and forth, incredible!",
+            ],
+            player_scored: vec![
+                "And it's in! What a finish!",
+                "The crowd is on its feet!",
+                "Oh, they never saw that coming!",
+            ],
+            ai_scored: vec![
+                "Shake it off. Next point is ours.",
+                "You were late on that one. Watch the angle.",
+                "Deep breath. Reset. We go again.",
+            ],
+            near_miss: vec![
+                "Ooh, by a whisker!",
+                "That was close enough to feel the breeze!",
+                "Millimetres! Absolutely millimetres!",
+            ],
+        }
+    }
+}
+
+// The line on screen, typed out a letter at a time
+#[derive(Resource, Default)]
+struct Dialog {
+    line: Option<(Persona, &'static str)>,
+    typed: f32,
+    shown_for: f32,
+}
+
+#[derive(Resource, Default)]
+struct DialogRally {
+    hits: u32,
+    // whether the ball was held for a serve last frame
+    was_frozen: bool,
+}
+
+#[derive(Component)]
+struct DialogBox;
+
+#[derive(Component)]
+struct DialogPortrait;
+
+#[derive(Component)]
+struct DialogFace;
+
+#[derive(Component)]
+struct DialogName;
+
+#[derive(Component)]
+struct DialogText;
+
+pub struct VisualNovelPlugin;
+
+impl Plugin for VisualNovelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VisualNovelMode>()
+            .init_resource::<DialogLines>()
+            .init_resource::<Dialog>()
+            .init_resource::<DialogRally>()
+            .add_systems(Startup, spawn_dialog_box)
+            .add_game_mode(KeyCode::KeyD, "mode_visual_novel", toggle_visual_novel_mode)
+            .add_systems(
+                Update,
+                (start_dialog.after(toggle_visual_novel_mode), type_dialog).chain(),
+            );
+    }
+}
+
+fn spawn_dialog_box(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(10.),
+                    left: Val::Percent(10.),
+                    width: Val::Percent(80.),
+                    padding: UiRect::all(Val::Px(12.)),
+                    column_gap: Val::Px(12.),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::srgba(0.05, 0.05, 0.1, 0.9).into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            DialogBox,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(PORTRAIT_SIZE),
+                            height: Val::Px(PORTRAIT_SIZE),
+                            flex_shrink: 0.,
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        border_radius: BorderRadius::MAX,
+                        ..default()
+                    },
+                    DialogPortrait,
+                ))
+                .with_children(|portrait| {
+                    portrait.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 24.,
+                                color: Color::BLACK,
+                                ..default()
+                            },
+                        ),
+                        DialogFace,
+                    ));
+                });
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|column| {
+                    column.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 18.,
+                                ..default()
+                            },
+                        ),
+                        DialogName,
+                    ));
+                    column.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 24.,
+                                color: Color::WHITE,
+                                ..default()
+                            },
+                        ),
+                        DialogText,
+                    ));
+                });
+        });
+}
+
+fn toggle_visual_novel_mode(mut mode: ResMut<VisualNovelMode>, mut dialog: ResMut<Dialog>) {
+    mode.0 = !mode.0;
+    dialog.line = None;
+    info!("Visual novel mode: {}", mode.0);
+}
+
+fn start_dialog(
+    mode: Res<VisualNovelMode>,
+    lines: Res<DialogLines>,
+    mut dialog: ResMut<Dialog>,
+    mut rally: ResMut<DialogRally>,
+    ball_frozen: Res<BallFrozen>,
+    mut paddle_hits: EventReader<PaddleHit>,
+    mut scored_events: EventReader<Scored>,
+    mut near_misses: EventReader<NearMiss>,
+) {
+    let hits_before = rally.hits;
+    rally.hits += paddle_hits.read().count() as u32;
+    let served = rally.was_frozen && !ball_frozen.0;
+    rally.was_frozen = ball_frozen.0;
+    let near_miss = near_misses.read().count() > 0;
+    let scored = scored_events.read().last().map(|event| event.0);
+    if scored.is_some() {
+        rally.hits = 0;
+    }
+
+    if !mode.0 {
+        return;
+    }
+
+    // the biggest moment of the frame gets the line
+    let (persona, pool) = if let Some(scorer) = scored {
+        match scorer {
+            Scorer::Player => (Persona::Commentator, &lines.player_scored),
+            Scorer::Ai => (Persona::Coach, &lines.ai_scored),
+        }
+    } else if near_miss {
+        (Persona::Commentator, &lines.near_miss)
+    } else if hits_before < LONG_RALLY_HITS && rally.hits >= LONG_RALLY_HITS {
+        (Persona::Commentator, &lines.long_rally)
+    } else if served {
+        (Persona::Coach, &lines.serve)
+    } else {
+        return;
+    };
+
+    if let Some(line) = pool.choose(&mut rand::thread_rng()) {
+        *dialog = Dialog {
+            line: Some((persona, line)),
+            ..default()
+        };
+    }
+}
+
+fn type_dialog(
+    mut dialog: ResMut<Dialog>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut dialog_box: Query<&mut Visibility, With<DialogBox>>,
+    mut portrait: Query<&mut BackgroundColor, With<DialogPortrait>>,
+    mut texts: ParamSet<(
+        Query<&mut Text, With<DialogFace>>,
+        Query<&mut Text, With<DialogName>>,
+        Query<&mut Text, With<DialogText>>,
+    )>,
+    time: Res<Time<Real>>,
+) {
+    let Ok(mut visibility) = dialog_box.get_single_mut() else {
+        return;
+    };
+    let Some((persona, line)) = dialog.line else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    // a line that just started isn't dismissed by the key that caused it
+    let dismissed = dialog.typed > 0. && keyboard_input.get_just_pressed().next().is_some();
+    if dismissed || dialog.shown_for >= DIALOG_SECS {
+        dialog.line = None;
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    // real time, so slow motion doesn't slow the typing down
+    let delta = time.delta_seconds();
+    let length = line.chars().count() as f32;
+    if dialog.typed < length {
+        dialog.typed = (dialog.typed + delta * CHARS_PER_SEC).min(length);
+    } else {
+        dialog.shown_for += delta;
+    }
+
+    *visibility = Visibility::Visible;
+    if let Ok(mut color) = portrait.get_single_mut() {
+        color.0 = persona.color();
+    }
+    if let Ok(mut text) = texts.p0().get_single_mut() {
+        text.sections[0].value = persona.face().to_string();
+    }
+    if let Ok(mut text) = texts.p1().get_single_mut() {
+        text.sections[0].value = persona.name().to_string();
+        text.sections[0].style.color = persona.color();
+    }
+    if let Ok(mut text) = texts.p2().get_single_mut() {
+        text.sections[0].value = line.chars().take(dialog.typed as usize).collect();
+    }
+}
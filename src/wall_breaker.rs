@@ -0,0 +1,232 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
+use bevy::math::bounding::{Aabb2d, BoundingCircle, IntersectsVolume};
+use bevy::prelude::*;
+
+use crate::game_state::{reset_resource, GameState, InMatch};
+use crate::{
+    detect_collisions, handle_collisions, Ball, Gutter, Position, Shape, Velocity, GUTTER_HEIGHT,
+};
+
+// slices along each gutter, top gutter first
+const GUTTER_SLICES: usize = 32;
+const GUTTERS: usize = 2;
+// px per step, hits any softer leave no mark
+const CRACK_THRESHOLD: f32 = 8.;
+const MAX_CRACK_LEVEL: u8 = 3;
+const OPEN_SLICE_SECS: f32 = 5.;
+const CRACK_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+// How cracked each slice of each gutter is
+#[derive(Resource, Default)]
+struct GutterDamage([[u8; GUTTER_SLICES]; GUTTERS]);
+
+// Slices knocked out of the gutters, until their timer runs out
+#[derive(Resource, Default)]
+struct OpenSlices(Vec<(usize, usize, Timer)>);
+
+// Stretches of a gutter, by x, that balls pass straight through
+#[derive(Component, Default)]
+pub struct GutterGaps(pub Vec<Range<f32>>);
+
+#[derive(Component)]
+struct GutterHole {
+    gutter: usize,
+    slice: usize,
+}
+
+pub struct WallBreakerPlugin;
+
+impl Plugin for WallBreakerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GutterDamage>()
+            .init_resource::<OpenSlices>()
+            // every match starts on whole gutters
+            .add_systems(
+                OnEnter(InMatch),
+                (reset_resource::<GutterDamage>, reset_resource::<OpenSlices>),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    crack_gutters.after(handle_collisions),
+                    close_open_slices,
+                    // gutters are respawned every point, so the gaps are put
+                    // back every step. A slice knocked open this step lets
+                    // balls through from the next.
+                    cut_gutter_gaps
+                        .after(close_open_slices)
+                        .before(detect_collisions),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, (draw_cracks, place_gutter_holes));
+    }
+}
+
+fn gutter_index(position: &Position) -> usize {
+    if position.0.y > 0. {
+        0
+    } else {
+        1
+    }
+}
+
+fn slice_width(shape: &Shape) -> f32 {
+    shape.0.x / GUTTER_SLICES as f32
+}
+
+fn slice_at(x: f32, position: &Position, shape: &Shape) -> usize {
+    let from_left = x - (position.0.x - shape.0.x / 2.);
+    ((from_left / slice_width(shape)) as usize).min(GUTTER_SLICES - 1)
+}
+
+fn slice_center(slice: usize, position: &Position, shape: &Shape) -> Vec2 {
+    let x = position.0.x - shape.0.x / 2. + (slice as f32 + 0.5) * slice_width(shape);
+    Vec2::new(x, position.0.y)
+}
+
+fn crack_gutters(
+    mut commands: Commands,
+    mut damage: ResMut<GutterDamage>,
+    mut open: ResMut<OpenSlices>,
+    mut touching: Local<HashSet<Entity>>,
+    balls: Query<(Entity, &Position, &Shape, &Velocity), With<Ball>>,
+    gutters: Query<(&Position, &Shape, Option<&GutterGaps>), (With<Gutter>, Without<Ball>)>,
+) {
+    let mut still_touching = HashSet::new();
+
+    for (entity, ball_position, ball_shape, velocity) in &balls {
+        let ball_circle = BoundingCircle::new(ball_position.0, ball_shape.0.x);
+        for (position, shape, gaps) in &gutters {
+            if !ball_circle.intersects(&Aabb2d::new(position.0, shape.0 / 2.)) {
+                continue;
+            }
+            // escaping through a hole isn't a hit
+            let in_gap =
+                gaps.is_some_and(|gaps| gaps.0.iter().any(|gap| gap.contains(&ball_position.0.x)));
+            if in_gap {
+                continue;
+            }
+            still_touching.insert(entity);
+
+            // only the step the ball strikes counts
+            if touching.contains(&entity) || velocity.0.length() <= CRACK_THRESHOLD {
+                continue;
+            }
+
+            let gutter = gutter_index(position);
+            let slice = slice_at(ball_position.0.x, position, shape);
+            let level = &mut damage.0[gutter][slice];
+            *level += 1;
+
+            if *level >= MAX_CRACK_LEVEL {
+                *level = 0;
+                info!("Gutter slice {slice} broke open");
+                open.0.push((
+                    gutter,
+                    slice,
+                    Timer::from_seconds(OPEN_SLICE_SECS, TimerMode::Once),
+                ));
+                commands.spawn((
+                    GutterHole { gutter, slice },
+                    StateScoped(InMatch),
+                    SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(Vec2::new(slice_width(shape), GUTTER_HEIGHT)),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                ));
+            }
+        }
+    }
+
+    *touching = still_touching;
+}
+
+fn close_open_slices(
+    mut commands: Commands,
+    mut open: ResMut<OpenSlices>,
+    holes: Query<(Entity, &GutterHole)>,
+    time: Res<Time>,
+) {
+    for (_, _, timer) in &mut open.0 {
+        timer.tick(time.delta());
+    }
+    for (entity, hole) in &holes {
+        let closed = open.0.iter().any(|(gutter, slice, timer)| {
+            *gutter == hole.gutter && *slice == hole.slice && timer.finished()
+        });
+        if closed {
+            commands.entity(entity).despawn();
+        }
+    }
+    open.0.retain(|(_, _, timer)| !timer.finished());
+}
+
+fn cut_gutter_gaps(
+    mut commands: Commands,
+    open: Res<OpenSlices>,
+    gutters: Query<(Entity, &Position, &Shape), With<Gutter>>,
+) {
+    for (entity, position, shape) in &gutters {
+        let gutter = gutter_index(position);
+        let half_slice = slice_width(shape) / 2.;
+        let gaps = open
+            .0
+            .iter()
+            .filter(|(open_gutter, ..)| *open_gutter == gutter)
+            .map(|(_, slice, _)| {
+                let center = slice_center(*slice, position, shape).x;
+                center - half_slice..center + half_slice
+            })
+            .collect();
+        commands.entity(entity).insert(GutterGaps(gaps));
+    }
+}
+
+// a thin dark line per crack level across the slice
+fn draw_cracks(
+    mut gizmos: Gizmos,
+    damage: Res<GutterDamage>,
+    gutters: Query<(&Position, &Shape), With<Gutter>>,
+) {
+    for (position, shape) in &gutters {
+        let levels = &damage.0[gutter_index(position)];
+        let half_slice = slice_width(shape) / 2.;
+
+        for (slice, level) in levels.iter().enumerate() {
+            let center = slice_center(slice, position, shape);
+            for crack in 0..*level {
+                let y = center.y + (crack as f32 - 1.) * GUTTER_HEIGHT / 4.;
+                gizmos.line_2d(
+                    Vec2::new(center.x - half_slice * 0.8, y),
+                    Vec2::new(center.x + half_slice * 0.8, y),
+                    CRACK_COLOR,
+                );
+            }
+        }
+    }
+}
+
+// Holes are drawn in the background colour over the gutter,
+// following it if it moves
+fn place_gutter_holes(
+    mut holes: Query<(&GutterHole, &mut Transform, &mut Sprite)>,
+    gutters: Query<(&Position, &Shape), With<Gutter>>,
+    clear_color: Res<ClearColor>,
+) {
+    for (hole, mut transform, mut sprite) in &mut holes {
+        sprite.color = clear_color.0;
+        let gutter = gutters
+            .iter()
+            .find(|(position, _)| gutter_index(position) == hole.gutter);
+        if let Some((position, shape)) = gutter {
+            let center = slice_center(hole.slice, position, shape);
+            transform.translation = center.extend(1.);
+        }
+    }
+}
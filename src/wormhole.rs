@@ -0,0 +1,152 @@
+use bevy::math::bounding::{BoundingCircle, IntersectsVolume};
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+use rand::random;
+
+use crate::game_state::{GameState, InMatch};
+use crate::powerups::{PowerupCollected, PowerupKind};
+use crate::{handle_collisions, Ball, Position, Shape, Velocity};
+
+const WORMHOLE_RADIUS: f32 = 20.;
+const WORMHOLE_SECS: f32 = 5.;
+const WORMHOLE_ALPHA: f32 = 0.6;
+const MAX_WORMHOLE_PAIRS: usize = 2;
+
+// One end of a pair, a ball entering it comes out of `partner`
+#[derive(Component)]
+struct Wormhole {
+    partner: Entity,
+    timer: Timer,
+}
+
+// Set on a ball coming out of a wormhole so it isn't sent straight
+// back, until it has cleared every wormhole
+#[derive(Component)]
+struct LeftWormhole;
+
+pub struct WormholePlugin;
+
+impl Plugin for WormholePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                open_wormholes.run_if(in_state(GameState::Playing)),
+                fade_wormholes,
+            ),
+        )
+        .add_systems(
+            FixedUpdate,
+            travel_through_wormholes
+                .after(handle_collisions)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+fn open_wormholes(
+    mut commands: Commands,
+    mut collected: EventReader<PowerupCollected>,
+    wormholes: Query<(), With<Wormhole>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    window: Query<&Window>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    let mut pairs = wormholes.iter().count() / 2;
+
+    for event in collected.read() {
+        if event.kind != PowerupKind::Wormhole || pairs >= MAX_WORMHOLE_PAIRS {
+            continue;
+        }
+        pairs += 1;
+
+        let mesh = meshes.add(Circle::new(WORMHOLE_RADIUS));
+        // both ends share a material, so they fade together
+        let material = materials.add(ColorMaterial::from_color(
+            Color::srgb_u8(150, 80, 230).with_alpha(WORMHOLE_ALPHA),
+        ));
+
+        let x = window.resolution.width() / 4.;
+        let random_y = || (random::<f32>() - 0.5) * window.resolution.height() * 0.8;
+        let ends = [Vec2::new(-x, random_y()), Vec2::new(x, random_y())];
+        let entities = ends.map(|_| commands.spawn_empty().id());
+
+        for (i, end) in ends.into_iter().enumerate() {
+            commands.entity(entities[i]).insert((
+                Wormhole {
+                    partner: entities[1 - i],
+                    timer: Timer::from_seconds(WORMHOLE_SECS, TimerMode::Once),
+                },
+                Position(end),
+                StateScoped(InMatch),
+                MaterialMesh2dBundle {
+                    mesh: mesh.clone().into(),
+                    material: material.clone(),
+                    transform: Transform::from_translation(end.extend(0.)),
+                    ..default()
+                },
+            ));
+        }
+    }
+}
+
+fn fade_wormholes(
+    mut commands: Commands,
+    mut wormholes: Query<(Entity, &mut Wormhole, &Handle<ColorMaterial>)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    time: Res<Time>,
+) {
+    for (entity, mut wormhole, material) in &mut wormholes {
+        if wormhole.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if let Some(material) = materials.get_mut(material) {
+            let alpha = WORMHOLE_ALPHA * wormhole.timer.fraction_remaining();
+            material.color.set_alpha(alpha);
+        }
+    }
+}
+
+fn travel_through_wormholes(
+    mut commands: Commands,
+    mut balls: Query<
+        (
+            Entity,
+            &mut Position,
+            &mut Velocity,
+            &Shape,
+            Has<LeftWormhole>,
+        ),
+        With<Ball>,
+    >,
+    wormholes: Query<(&Wormhole, &Position), Without<Ball>>,
+) {
+    for (entity, mut position, mut velocity, shape, left_wormhole) in &mut balls {
+        let ball_circle = BoundingCircle::new(position.0, shape.0.x);
+        let entered = wormholes.iter().find(|(_, wormhole_position)| {
+            ball_circle.intersects(&BoundingCircle::new(wormhole_position.0, WORMHOLE_RADIUS))
+        });
+
+        let Some((wormhole, _)) = entered else {
+            if left_wormhole {
+                commands.entity(entity).remove::<LeftWormhole>();
+            }
+            continue;
+        };
+        if left_wormhole {
+            continue;
+        }
+
+        // the other end may have just closed
+        if let Ok((_, exit)) = wormholes.get(wormhole.partner) {
+            position.0 = exit.0;
+            velocity.0.y *= -1.;
+            commands.entity(entity).insert(LeftWormhole);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+
+use crate::game_state::{AddGameMode, GameState};
+use crate::{detect_collisions, move_ball, Ball, Position, Velocity, GUTTER_HEIGHT};
+
+// Rail ends as fractions of the field's half width and half height.
+// The set looks the same from either side, so neither player gets
+// the better rails.
+const RAILS: [(Vec2, Vec2); 4] = [
+    (Vec2::new(-1., -0.7), Vec2::new(1., 0.7)),
+    (Vec2::new(-1., 0.7), Vec2::new(1., -0.7)),
+    (Vec2::new(-1., -0.1), Vec2::new(1., 0.9)),
+    (Vec2::new(-1., -0.9), Vec2::new(1., 0.1)),
+];
+
+// Game variant where the ball can only ride along a few fixed
+// diagonal rails, switching rails wherever two of them cross
+#[derive(Resource, Default)]
+struct ZiplineMode(bool);
+
+// The rail a ball is riding, as an index into `RAILS`. Which way
+// along it follows the ball's horizontal heading, so a paddle hit
+// sends it back the way it came.
+#[derive(Component)]
+struct OnRail(usize);
+
+#[derive(Copy, Clone)]
+struct Rail {
+    start: Vec2,
+    end: Vec2,
+}
+
+impl Rail {
+    fn direction(&self) -> Vec2 {
+        (self.end - self.start).normalize()
+    }
+
+    // onto the rail's line rather than the segment, so a ball
+    // that gets past a paddle carries on off the end to score
+    fn project(&self, point: Vec2) -> Vec2 {
+        let direction = self.direction();
+        self.start + direction * (point - self.start).dot(direction)
+    }
+
+    fn intersection(&self, other: &Rail) -> Option<Vec2> {
+        let (d, e) = (self.end - self.start, other.end - other.start);
+        let cross = d.perp_dot(e);
+        if cross.abs() < f32::EPSILON {
+            return None;
+        }
+        let offset = other.start - self.start;
+        let t = offset.perp_dot(e) / cross;
+        let s = offset.perp_dot(d) / cross;
+        ((0. ..=1.).contains(&t) && (0. ..=1.).contains(&s)).then(|| self.start + d * t)
+    }
+}
+
+pub struct ZiplinePlugin;
+
+impl Plugin for ZiplinePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ZiplineMode>()
+            .add_game_mode(KeyCode::KeyQ, "mode_zipline", toggle_zipline_mode)
+            .add_systems(Update, draw_rails)
+            .add_systems(
+                FixedUpdate,
+                ride_rails
+                    .after(move_ball)
+                    .before(detect_collisions)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn rails(window: &Window) -> [Rail; 4] {
+    // clear of the gutters so a railed ball never touches them
+    let half_size = Vec2::new(
+        window.resolution.width() / 2.,
+        window.resolution.height() / 2. - GUTTER_HEIGHT * 2.,
+    );
+    RAILS.map(|(start, end)| Rail {
+        start: start * half_size,
+        end: end * half_size,
+    })
+}
+
+fn toggle_zipline_mode(mut mode: ResMut<ZiplineMode>) {
+    mode.0 = !mode.0;
+    info!("Zipline mode: {}", mode.0);
+}
+
+fn draw_rails(mode: Res<ZiplineMode>, window: Query<&Window>, mut gizmos: Gizmos) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    if !mode.0 {
+        return;
+    }
+
+    for rail in rails(window) {
+        gizmos.line_2d(rail.start, rail.end, Color::srgba(0.6, 0.6, 0.7, 0.5));
+    }
+}
+
+// Runs after `move_ball`, pulling the ball back onto its rail and
+// lining its velocity up with the rail for the next step
+fn ride_rails(
+    mut commands: Commands,
+    mode: Res<ZiplineMode>,
+    mut balls: Query<(Entity, &mut Position, &mut Velocity, Option<&mut OnRail>), With<Ball>>,
+    window: Query<&Window>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    if !mode.0 {
+        return;
+    }
+    let rails = rails(window);
+
+    for (entity, mut position, mut velocity, on_rail) in &mut balls {
+        let mut index = match &on_rail {
+            Some(on_rail) => on_rail.0,
+            // a freshly served ball takes the nearest rail
+            None => (0..rails.len())
+                .min_by(|&a, &b| {
+                    let distance = |i: usize| rails[i].project(position.0).distance(position.0);
+                    distance(a).total_cmp(&distance(b))
+                })
+                .unwrap_or(0),
+        };
+
+        let previous = position.0 - velocity.0;
+        let heading = velocity.0.x.signum() * rails[index].direction().x.signum();
+        let forward = rails[index].direction() * heading;
+
+        // crossing another rail this step moves the ball onto it
+        let crossing = rails.iter().enumerate().find_map(|(other, rail)| {
+            let point = rails[index].intersection(rail)?;
+            let passed =
+                (previous - point).dot(forward) < 0. && (position.0 - point).dot(forward) >= 0.;
+            (other != index && passed).then_some(other)
+        });
+        if let Some(other) = crossing {
+            index = other;
+        }
+
+        let rail = rails[index];
+        let heading = velocity.0.x.signum() * rail.direction().x.signum();
+        position.0 = rail.project(position.0);
+        velocity.0 = rail.direction() * heading * velocity.0.length();
+
+        match on_rail {
+            Some(mut on_rail) => on_rail.0 = index,
+            None => {
+                commands.entity(entity).insert(OnRail(index));
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::double_width::PlayfieldScaleAnimation;
+use crate::{Ball, Paddle, Position, GUTTER_HEIGHT};
+
+// horizontal distance between ball and paddle that triggers the zoom
+const ZOOM_DISTANCE: f32 = 50.;
+const ZOOMED_SCALE: f32 = 0.7;
+const ZOOM_SMOOTHING: f32 = 3.;
+
+pub struct ZoomCameraPlugin;
+
+impl Plugin for ZoomCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, zoom_camera);
+    }
+}
+
+fn zoom_camera(
+    mut camera: Query<&mut OrthographicProjection, With<Camera2d>>,
+    balls: Query<&Position, With<Ball>>,
+    paddles: Query<&Position, With<Paddle>>,
+    window: Query<&Window>,
+    playfield: Res<PlayfieldScaleAnimation>,
+    time: Res<Time>,
+) {
+    let (Ok(mut projection), Ok(window)) = (camera.get_single_mut(), window.get_single()) else {
+        return;
+    };
+
+    let ball_near_paddle = balls.iter().any(|ball| {
+        paddles
+            .iter()
+            .any(|paddle| (ball.0.x - paddle.0.x).abs() < ZOOM_DISTANCE)
+    });
+
+    // the camera zooms on the centre of the field, so never zoom
+    // past the point where the inner edges of the gutters leave view
+    let window_height = window.resolution.height();
+    let min_scale = (window_height - 2. * GUTTER_HEIGHT) / window_height;
+
+    let target = if ball_near_paddle {
+        ZOOMED_SCALE.max(min_scale)
+    } else {
+        1.
+    };
+    // zoomed out as far as the field has widened
+    let target = target * playfield.scale;
+
+    let smoothing = (time.delta_seconds() * ZOOM_SMOOTHING).min(1.);
+    projection.scale = projection.scale.lerp(target, smoothing);
+}